@@ -1,10 +1,102 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file7
 
+use std::fmt;
+
 pub fn divide(x: i32, y: i32) -> (i32 , i32){
     (x / y , x % y)
 }
 
+//* how the quotient is rounded when the division is not exact; the remainder always satisfies `x == q * y + r`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    //* toward zero, the `/` and `%` of rust: -7 / 2 = (-3, -1)
+    Truncate,
+    //* toward negative infinity, the remainder has the sign of `y`: -7 / 2 = (-4, 1)
+    Floor,
+    //* the remainder is never negative: -7 / -2 = (4, 1)
+    Euclid,
+    //* toward positive infinity, the remainder has the opposite sign of `y`: 7 / 2 = (4, -1)
+    Ceil,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivError {
+    DivisionByZero,
+    //* the quotient does not fit in the type: `i32::MIN / -1`
+    Overflow,
+    //* `Ceil` on an unsigned type when the division is not exact: the quotient fits, but the remainder
+    //* would be negative. `u32::div_ceil` and friends give the quotient alone
+    NegativeRemainder,
+}
+
+impl fmt::Display for DivError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DivError::DivisionByZero => write!(f, "attempt to divide by zero"),
+            DivError::Overflow => write!(f, "division result does not fit in the type"),
+            DivError::NegativeRemainder => write!(f, "the remainder is negative and the type is unsigned"),
+        }
+    }
+}
+
+impl std::error::Error for DivError {}
+
+pub trait CheckedDivRem: Sized + Copy {
+    fn div_rem(self, rhs: Self, rounding: Rounding) -> Result<(Self, Self), DivError>;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl CheckedDivRem for $t {
+            fn div_rem(self, rhs: Self, rounding: Rounding) -> Result<(Self, Self), DivError> {
+                if rhs == 0 {
+                    return Err(DivError::DivisionByZero);
+                }
+                //* `MIN / -1` is the only signed division that overflows, every other case below stays in range
+                let q = self.checked_div(rhs).ok_or(DivError::Overflow)?;
+                let r = self % rhs;
+                Ok(match rounding {
+                    Rounding::Truncate => (q, r),
+                    Rounding::Floor if r != 0 && (r < 0) != (rhs < 0) => (q - 1, r + rhs),
+                    Rounding::Ceil if r != 0 && (r < 0) == (rhs < 0) => (q + 1, r - rhs),
+                    Rounding::Euclid if r < 0 => {
+                        if rhs > 0 { (q - 1, r + rhs) } else { (q + 1, r - rhs) }
+                    }
+                    _ => (q, r),
+                })
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl CheckedDivRem for $t {
+            fn div_rem(self, rhs: Self, rounding: Rounding) -> Result<(Self, Self), DivError> {
+                if rhs == 0 {
+                    return Err(DivError::DivisionByZero);
+                }
+                let (q, r) = (self / rhs, self % rhs);
+                match rounding {
+                    //* rounding up leaves `r - rhs`, which is negative and has no unsigned representation
+                    Rounding::Ceil if r != 0 => Err(DivError::NegativeRemainder),
+                    _ => Ok((q, r)),
+                }
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize);
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+pub fn checked_divide<T: CheckedDivRem>(x: T, y: T, rounding: Rounding) -> Result<(T, T), DivError> {
+    x.div_rem(y, rounding)
+}
+
 /*
     * Q1 : why the tupple type exits in rust?
     - The tuple type in Rust is a way to group together multiple values of different types into a single compound type; ex` (String, i32) `. It is useful for returning multiple values from a function, as in this case where we want to return both the quotient and the remainder of a division operation. Tuples can also be used to group related data together without needing to define a separate struct, and they can be easily destructured to access individual elements.
+    * Q2 : why do we need more than one way of rounding?
+    - Rust's `/` truncates toward zero, so `-7 % 3 == -1`. That is fine for indexes but wrong for things like "how much is owed inside the current billing cycle", where a negative balance must still land in `0..3`; floored (sign of the divisor) and euclidean (never negative) division give that. Ceiling division answers "how many pages/boxes do i need".
 */
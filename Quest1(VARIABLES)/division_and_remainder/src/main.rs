@@ -8,4 +8,50 @@ fn main() {
         "{}/{}: division = {}, remainder = {}",
         x, y, division, remainder
     );
-}
\ No newline at end of file
+
+    match checked_divide(-7i64, 2, Rounding::Floor) {
+        Ok((q, r)) => println!("-7/2 floored: division = {}, remainder = {}", q, r),
+        Err(e) => println!("-7/2 floored: {}", e),
+    }
+    println!("i32::MIN/-1: {:?}", checked_divide(i32::MIN, -1, Rounding::Truncate));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_roundings() {
+        let cases: [(i32, i32); 6] = [(7, 2), (-7, 2), (7, -2), (-7, -2), (6, 3), (-6, 3)];
+        for (x, y) in cases {
+            let (t, f, e, c) = (
+                checked_divide(x, y, Rounding::Truncate).unwrap(),
+                checked_divide(x, y, Rounding::Floor).unwrap(),
+                checked_divide(x, y, Rounding::Euclid).unwrap(),
+                checked_divide(x, y, Rounding::Ceil).unwrap(),
+            );
+            assert_eq!(t, (x / y, x % y));
+            assert_eq!(e, (x.div_euclid(y), x.rem_euclid(y)));
+            assert_eq!(f.0, (x as f64 / y as f64).floor() as i32);
+            assert_eq!(c.0, (x as f64 / y as f64).ceil() as i32);
+            for (q, r) in [t, f, e, c] {
+                assert_eq!(q * y + r, x);
+            }
+        }
+        assert_eq!(checked_divide(-7, 2, Rounding::Floor), Ok((-4, 1)));
+        assert_eq!(checked_divide(7, -2, Rounding::Floor), Ok((-4, -1)));
+        assert_eq!(checked_divide(7, 2, Rounding::Ceil), Ok((4, -1)));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(checked_divide(1u8, 0, Rounding::Truncate), Err(DivError::DivisionByZero));
+        assert_eq!(checked_divide(i32::MIN, -1, Rounding::Floor), Err(DivError::Overflow));
+        assert_eq!(checked_divide(i8::MIN, -1, Rounding::Euclid), Err(DivError::Overflow));
+        assert_eq!(checked_divide(7u32, 2, Rounding::Ceil), Err(DivError::NegativeRemainder));
+        assert_eq!(7u32.div_ceil(2), 4);
+        assert_eq!(checked_divide(u8::MAX, 2, Rounding::Floor), Ok((127, 1)));
+        assert_eq!(checked_divide(8u32, 2, Rounding::Ceil), Ok((4, 0)));
+        assert_eq!(checked_divide(i128::MIN, 1, Rounding::Ceil), Ok((i128::MIN, 0)));
+    }
+}
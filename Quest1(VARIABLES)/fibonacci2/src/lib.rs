@@ -1,18 +1,202 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file7
 
+//* F(47) is the last fibonacci number that fits in a u32
 pub fn fibonacci(n: u32) -> u32 {
-    if n == 1 {
-        return 1
+    checked_fibonacci(n as u64)
+        .and_then(|f| u32::try_from(f).ok())
+        .unwrap_or_else(|| panic!("F({}) does not fit in a u32", n))
+}
+
+//* yields F(0), F(1), F(2), ... and stops after F(186), the last one that fits in a u128
+#[derive(Debug, Clone)]
+pub struct Fibonacci {
+    curr: Option<u128>,
+    next: Option<u128>,
+}
+
+impl Fibonacci {
+    pub fn new() -> Self {
+        Fibonacci { curr: Some(0), next: Some(1) }
+    }
+}
+
+impl Default for Fibonacci {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        let curr = self.curr?;
+        self.curr = self.next;
+        self.next = self.next.and_then(|next| next.checked_add(curr));
+        Some(curr)
     }
+}
+
+//* fast doubling: F(2k) = F(k) * (2F(k+1) - F(k)) and F(2k+1) = F(k)² + F(k+1)², so F(n) needs O(log n) steps
+//* returns None as soon as F(n) would overflow
+pub fn checked_fibonacci(n: u64) -> Option<u128> {
+    fn pair(n: u64) -> Option<(u128, u128)> {
+        if n == 0 {
+            return Some((0, 1));
+        }
+        let (a, b) = pair(n >> 1)?;
+        let c = a.checked_mul(b.checked_mul(2)? - a)?;
+        let d = a.checked_mul(a)?.checked_add(b.checked_mul(b)?)?;
+        if n & 1 == 0 {
+            Some((c, d))
+        } else {
+            Some((d, c.checked_add(d)?))
+        }
+    }
+    //* the pair for n - 1 is (F(n-1), F(n)), so nothing larger than F(n) is ever computed
+    match n {
+        0 => Some(0),
+        _ => pair(n - 1).map(|(_, f)| f),
+    }
+}
+
+//* F(n) mod m in O(log n), all the products are done in u128 so any u64 modulus is safe; None when m is 0
+pub fn fib_mod(n: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    if m == 1 {
+        return Some(0);
+    }
+    //* F(n) mod m repeats with the pisano period (<= 6m), so a huge n can be reduced below it. Finding
+    //* the period walks the sequence, so it is only done for small moduli and an n past the bound
+    let n = if m <= PISANO_LIMIT && n / 6 > m { pisano_period(m).map_or(n, |p| n % p) } else { n };
+    Some(fib_mod_pair(n, m as u128).0 as u64)
+}
+
+//* the period walk is at most 6 * 1024 steps
+const PISANO_LIMIT: u64 = 1 << 10;
+
+fn fib_mod_pair(n: u64, m: u128) -> (u128, u128) {
     if n == 0 {
-        return 0
+        return (0, 1);
+    }
+    let (a, b) = fib_mod_pair(n >> 1, m);
+    let c = a * ((2 * b + m - a) % m) % m;
+    //* each square is below m² but their sum may not fit in a u128 when m is close to u64::MAX
+    let d = ((a * a) % m + (b * b) % m) % m;
+    if n & 1 == 0 {
+        (c, d)
+    } else {
+        (d, (c + d) % m)
     }
+}
+
+//* the period of F(n) mod m; it is always <= 6m and is found by walking the sequence, so this takes
+//* up to 6m steps: fine for m up to about 10^8, minutes beyond that. None when m is 0 or 6m does not fit in a u64
+pub fn pisano_period(m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    if m == 1 {
+        return Some(1);
+    }
+    let bound = m.checked_mul(6)?;
+    let (mut a, mut b) = (0u64, 1u64);
+    for i in 1..=bound {
+        (a, b) = (b, ((a as u128 + b as u128) % m as u128) as u64);
+        if a == 0 && b == 1 {
+            return Some(i);
+        }
+    }
+    unreachable!("the pisano period of {} is bounded by {}", m, bound)
+}
+
+//* exact F(n) for any n as a decimal string, using fast doubling over base 10^9 limbs (least significant first)
+pub fn fibonacci_big(n: u64) -> String {
+    fn pair(n: u64) -> (Vec<u64>, Vec<u64>) {
+        if n == 0 {
+            return (vec![0], vec![1]);
+        }
+        let (a, b) = pair(n >> 1);
+        let c = mul(&a, &sub(&add(&b, &b), &a));
+        let d = add(&mul(&a, &a), &mul(&b, &b));
+        if n & 1 == 0 {
+            (c, d)
+        } else {
+            let e = add(&c, &d);
+            (d, e)
+        }
+    }
+    let (f, _) = pair(n);
+    let mut out = f.last().unwrap().to_string();
+    for limb in f.iter().rev().skip(1) {
+        out.push_str(&format!("{:09}", limb));
+    }
+    out
+}
+
+const BASE: u64 = 1_000_000_000;
 
-    return fibonacci(n-1) + fibonacci(n-2);
+fn trim(mut v: Vec<u64>) -> Vec<u64> {
+    while v.len() > 1 && *v.last().unwrap() == 0 {
+        v.pop();
+    }
+    v
+}
+
+fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let s = a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0) + carry;
+        out.push(s % BASE);
+        carry = s / BASE;
+    }
+    if carry > 0 {
+        out.push(carry);
+    }
+    out
+}
+
+//* a - b, the callers guarantee a >= b
+fn sub(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let y = b.get(i).unwrap_or(&0) + borrow;
+        if x >= y {
+            out.push(x - y);
+            borrow = 0;
+        } else {
+            out.push(x + BASE - y);
+            borrow = 1;
+        }
+    }
+    trim(out)
+}
+
+fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let t = out[i + j] + x * y + carry;
+            out[i + j] = t % BASE;
+            carry = t / BASE;
+        }
+        out[i + b.len()] += carry;
+    }
+    trim(out)
 }
 
 /*
     * Where Fibonacci and golden ratio actually appear / are used in real life:
     - In nature: The arrangement of leaves on a stem, the branching of trees, the arrangement of seeds in a sunflower, all exhibit patterns that can be described using Fibonacci numbers and the golden ratio.
         like they leaves are arranged in a way that they don't block each other from sunlight, and the seeds in a sunflower are arranged in a way that they can fit as many seeds as possible in the available space.
-*/
\ No newline at end of file
+    * Why was the recursive version so slow?
+    - fibonacci(n-1) + fibonacci(n-2) recomputes the same values again and again, the number of calls grows like F(n) itself (~1.6^n), so n = 40 already means hundreds of millions of calls.
+        the iterator keeps only the last two values (O(n)), and fast doubling jumps from F(k) to F(2k) (O(log n)), which is why F(10^18) mod p takes about 60 steps.
+    * What is the pisano period?
+    - taken modulo m, the fibonacci sequence is periodic because there are only m² possible pairs (F(k), F(k+1)); the length of that cycle is the pisano period (π(10) = 60).
+*/
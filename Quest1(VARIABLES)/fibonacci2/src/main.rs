@@ -21,4 +21,81 @@ fn main() {
         20,
         fibonacci(20)
     );
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_values() {
+        let first: Vec<u32> = (0..10).map(fibonacci).collect();
+        assert_eq!(first, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        assert_eq!(fibonacci(47), 2971215073);
+    }
+
+    #[test]
+    fn test_iterator_matches_fast_doubling() {
+        let all: Vec<u128> = Fibonacci::new().collect();
+        assert_eq!(all.len(), 187);
+        for (n, f) in all.iter().enumerate() {
+            assert_eq!(checked_fibonacci(n as u64), Some(*f));
+        }
+        assert_eq!(all[186], 332825110087067562321196029789634457848);
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        assert!(checked_fibonacci(186).is_some());
+        assert_eq!(checked_fibonacci(187), None);
+        assert_eq!(checked_fibonacci(u64::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_u32_overflow_panics() {
+        fibonacci(48);
+    }
+
+    #[test]
+    fn test_fib_mod() {
+        assert_eq!(fib_mod(10u64.pow(18), 1_000_000_007), Some(209783453));
+        assert_eq!(fib_mod(10u64.pow(18), 1000), Some(875));
+        assert_eq!(fib_mod(u64::MAX, 1), Some(0));
+        assert_eq!(fib_mod(5, 0), None);
+        for n in 0..=186 {
+            assert_eq!(fib_mod(n, 97).map(u128::from), Some(checked_fibonacci(n).unwrap() % 97));
+        }
+        //* near u64::MAX the sum of the two squares no longer fits in a u128
+        for m in [u64::MAX - 58, u64::MAX] {
+            for n in [93, 150, 186] {
+                assert_eq!(fib_mod(n, m).map(u128::from), Some(checked_fibonacci(n).unwrap() % m as u128));
+            }
+        }
+        //* on both sides of where the pisano reduction kicks in, and past the moduli it is used for
+        for m in [2, 10, 97, 1000, 1024, 1025] {
+            let period = pisano_period(m).unwrap();
+            for n in [6 * m + 5, 6 * m + 6, 10u64.pow(18), u64::MAX] {
+                assert_eq!(fib_mod(n, m), fib_mod(n % period, m), "F({}) mod {}", n, m);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pisano_period() {
+        assert_eq!(pisano_period(2), Some(3));
+        assert_eq!(pisano_period(10), Some(60));
+        assert_eq!(pisano_period(1000), Some(1500));
+        assert_eq!(pisano_period(u64::MAX / 6 + 1), None);
+        assert_eq!(pisano_period(0), None);
+    }
+
+    #[test]
+    fn test_big() {
+        assert_eq!(fibonacci_big(0), "0");
+        assert_eq!(fibonacci_big(186), checked_fibonacci(186).unwrap().to_string());
+        assert_eq!(
+            fibonacci_big(300),
+            "222232244629420445529739893461909967206666939096499764990979600"
+        );
+    }
+}
@@ -1,18 +1,180 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
 
-pub fn factorial(num: u64) -> u64 {
-    if num > 20 {
-        panic!("Input number is too large to compute factorial without overflow. 2^64 - 1 is the maximum value for u64, and 20! is the largest factorial that fits within this limit.");
+mod big;
+pub use big::BigUint;
+
+use std::fmt;
+
+//* 20! is the largest factorial that fits in a u64
+pub const MAX_U64_FACTORIAL: u64 = 20;
+//* upper bound for the exact big results and the sieve behind the factorization, 10^6! already has ~5.5 million digits
+pub const MAX_BIG_INPUT: u64 = 1_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FactorialError {
+    Overflow(u64),
+    TooLarge(u64),
+    ZeroModulus,
+    NotPrime(u64),
+}
+
+impl fmt::Display for FactorialError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FactorialError::Overflow(n) => write!(f, "{}! does not fit in a u64, the limit is {}!", n, MAX_U64_FACTORIAL),
+            FactorialError::TooLarge(n) => write!(f, "{} is above the supported limit of {}", n, MAX_BIG_INPUT),
+            FactorialError::ZeroModulus => write!(f, "the modulus must be positive"),
+            FactorialError::NotPrime(p) => write!(f, "{} is not a prime number", p),
+        }
+    }
+}
+
+impl std::error::Error for FactorialError {}
+
+pub fn factorial(num: u64) -> Result<u64, FactorialError> {
+    if num > MAX_U64_FACTORIAL {
+        return Err(FactorialError::Overflow(num));
+    }
+    Ok((1..=num).product())
+}
+
+fn check_big_input(n: u64) -> Result<(), FactorialError> {
+    if n > MAX_BIG_INPUT {
+        return Err(FactorialError::TooLarge(n));
+    }
+    Ok(())
+}
+
+//* multiplies the numbers yielded by `factors` into one BigUint, packing them into u32 chunks first so most steps are plain u64 math
+fn product<I: Iterator<Item = u64>>(factors: I) -> BigUint {
+    let mut result = BigUint::one();
+    let mut chunk = 1u64;
+    for factor in factors {
+        if chunk * factor > u32::MAX as u64 {
+            result.mul_small(chunk as u32);
+            chunk = 1;
+        }
+        chunk *= factor;
+    }
+    result.mul_small(chunk as u32);
+    result
+}
+
+pub fn big_factorial(n: u64) -> Result<BigUint, FactorialError> {
+    check_big_input(n)?;
+    Ok(product(2..=n))
+}
+
+//* n!! = n * (n - 2) * (n - 4) * ... down to 1 or 2
+pub fn double_factorial(n: u64) -> Result<BigUint, FactorialError> {
+    check_big_input(n)?;
+    Ok(product((1..=n).rev().step_by(2)))
+}
+
+//* C(n, k) built from the prime factorization of n! / (k! (n - k)!) so no division of big numbers is needed
+pub fn binomial(n: u64, k: u64) -> Result<BigUint, FactorialError> {
+    check_big_input(n)?;
+    if k > n {
+        return Ok(BigUint::zero());
     }
+    let factors = primes_up_to(n).into_iter().flat_map(|p| {
+        let exp = legendre_exponent(n, p) - legendre_exponent(k, p) - legendre_exponent(n - k, p);
+        std::iter::repeat_n(p, exp as usize)
+    });
+    Ok(product(factors))
+}
 
-    if num == 0 || num == 1 {
-        return 1;
+//* n! mod m, once n >= m the modulus itself is one of the factors so the answer is 0.
+//* Otherwise it takes up to n multiplications, stopping early when the product reaches 0
+//* (m = 2^63 is there after 65 of them), but for a large prime m there is no shortcut
+pub fn factorial_mod(n: u64, m: u64) -> Result<u64, FactorialError> {
+    if m == 0 {
+        return Err(FactorialError::ZeroModulus);
+    }
+    if n >= m {
+        return Ok(0);
     }
+    let mut acc = 1 % m;
+    for i in 2..=n {
+        acc = (acc as u128 * i as u128 % m as u128) as u64;
+        if acc == 0 {
+            break;
+        }
+    }
+    Ok(acc)
+}
+
+//* legendre's formula: the exponent of the prime p in n! is ⌊n/p⌋ + ⌊n/p²⌋ + ⌊n/p³⌋ + ...
+pub fn legendre(n: u64, p: u64) -> Result<u64, FactorialError> {
+    if !is_prime(p) {
+        return Err(FactorialError::NotPrime(p));
+    }
+    Ok(legendre_exponent(n, p))
+}
+
+fn legendre_exponent(n: u64, p: u64) -> u64 {
+    let mut exp = 0;
+    let mut rest = n;
+    while rest >= p {
+        rest /= p;
+        exp += rest;
+    }
+    exp
+}
 
-    return factorial(num - 1) * num;
+//* the prime factorization of n! as (prime, exponent) pairs in increasing order
+pub fn factorial_factorization(n: u64) -> Result<Vec<(u64, u64)>, FactorialError> {
+    check_big_input(n)?;
+    Ok(primes_up_to(n).into_iter().map(|p| (p, legendre_exponent(n, p))).collect())
+}
+
+//* every trailing zero is a factor 10 = 2 * 5, and n! always has more 2s than 5s
+pub fn trailing_zeros(n: u64) -> u64 {
+    legendre_exponent(n, 5)
+}
+
+//* trial division by 2 and the odd numbers up to √n: about 2^31 steps for a prime near u64::MAX
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n.is_multiple_of(2) {
+        return n == 2;
+    }
+    let mut d = 3;
+    //* d * d would overflow once d passes 2^32
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            return false;
+        }
+        d += 2;
+    }
+    true
+}
+
+fn primes_up_to(n: u64) -> Vec<u64> {
+    let n = n as usize;
+    let mut composite = vec![false; n + 1];
+    let mut primes = Vec::new();
+    for i in 2..=n {
+        if composite[i] {
+            continue;
+        }
+        primes.push(i as u64);
+        let mut j = i * i;
+        while j <= n {
+            composite[j] = true;
+            j += i;
+        }
+    }
+    primes
 }
 
 /*
 * Q1. What happens if the input number is greater than 20?
     -A1. The factorial of numbers greater than 20 will exceed the maximum value that can be stored in a u64, leading to an overflow and incorrect results. In practice, you might want to handle this case to prevent such issues. 
-*/ 
\ No newline at end of file
+* Q2. How does BigUint get past that limit?
+    -A2. It stores the number as a list of 32-bit "digits" (limbs) and does the schoolbook carries by hand, the same way you multiply on paper but in base 2^32. Multiplying a limb by a u32 always fits in a u64, which is why the carry never overflows.
+* Q3. Why go through prime factorizations for C(n, k)?
+    -A3. n! / (k! (n-k)!) would need a big-number division; subtracting the legendre exponents instead gives the factorization of the result directly, and only multiplications are left.
+*/ 
@@ -1,9 +1,84 @@
 use find_factorial::*;
 
 fn main() {
-    println!("The factorial of 0 = {}", factorial(0));
-    println!("The factorial of 1 = {}", factorial(1));
-    println!("The factorial of 5 = {}", factorial(5));
-    println!("The factorial of 10 = {}", factorial(10));
-    println!("The factorial of 19 = {}", factorial(19));
-}
\ No newline at end of file
+    for n in [0, 1, 5, 10, 19, 21] {
+        match factorial(n) {
+            Ok(f) => println!("The factorial of {} = {}", n, f),
+            Err(e) => println!("The factorial of {}: {}", n, e),
+        }
+    }
+    if let Ok(f) = big_factorial(30) {
+        println!("The factorial of 30 = {}", f);
+    }
+    println!("100! ends with {} zeros", trailing_zeros(100));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u64_factorial() {
+        assert_eq!(factorial(0), Ok(1));
+        assert_eq!(factorial(20), Ok(2432902008176640000));
+        assert_eq!(factorial(21), Err(FactorialError::Overflow(21)));
+    }
+
+    #[test]
+    fn test_big_factorial() {
+        for n in 0..=20 {
+            assert_eq!(big_factorial(n).unwrap().to_u64(), factorial(n).ok());
+        }
+        assert_eq!(big_factorial(25).unwrap().to_string(), "15511210043330985984000000");
+        assert_eq!(big_factorial(1000).unwrap().to_string().len(), 2568);
+        let f3000 = big_factorial(3000).unwrap().to_string();
+        assert_eq!(f3000.len(), 9131);
+        assert!(f3000.starts_with("41493596034378540855"));
+        assert_eq!(big_factorial(MAX_BIG_INPUT + 1), Err(FactorialError::TooLarge(MAX_BIG_INPUT + 1)));
+    }
+
+    #[test]
+    fn test_binomial_and_double_factorial() {
+        assert_eq!(binomial(100, 50).unwrap().to_string(), "100891344545564193334812497256");
+        assert!(binomial(1000, 500).unwrap().to_string().ends_with("799821216320"));
+        assert_eq!(binomial(5, 0).unwrap(), BigUint::one());
+        assert_eq!(binomial(5, 6).unwrap(), BigUint::zero());
+        assert_eq!(double_factorial(9).unwrap().to_u64(), Some(945));
+        assert_eq!(double_factorial(10).unwrap().to_u64(), Some(3840));
+        assert_eq!(double_factorial(0).unwrap().to_u64(), Some(1));
+    }
+
+    #[test]
+    fn test_factorial_mod() {
+        //* wilson's theorem: (p - 1)! ≡ -1 (mod p)
+        assert_eq!(factorial_mod(6, 7), Ok(6));
+        assert_eq!(factorial_mod(1_000_002, 1_000_003), Ok(1_000_002));
+        assert_eq!(factorial_mod(10, 7), Ok(0));
+        assert_eq!(factorial_mod(0, 1), Ok(0));
+        assert_eq!(factorial_mod(3, 0), Err(FactorialError::ZeroModulus));
+        assert_eq!(factorial_mod(u64::MAX, 1_000_003), Ok(0));
+        assert_eq!(factorial_mod((1 << 63) - 1, 1 << 63), Ok(0));
+        //* u64::MAX = 3 · 5 · 17 · 257 · 641 · 65537 · 6700417, all of them are passed well before n
+        assert_eq!(factorial_mod(u64::MAX - 1, u64::MAX), Ok(0));
+    }
+
+    #[test]
+    fn test_factorization() {
+        assert_eq!(factorial_factorization(10), Ok(vec![(2, 8), (3, 4), (5, 2), (7, 1)]));
+        assert_eq!(legendre(100, 2), Ok(97));
+        assert_eq!(legendre(100, 4), Err(FactorialError::NotPrime(4)));
+        assert_eq!(legendre(100, 1), Err(FactorialError::NotPrime(1)));
+        assert_eq!(legendre(4_294_967_311, 4_294_967_311), Ok(1));
+        assert_eq!(legendre(u64::MAX, 4_294_967_297), Err(FactorialError::NotPrime(4_294_967_297)));
+        assert_eq!(trailing_zeros(1000), 249);
+        let zeros = big_factorial(1000).unwrap().to_string().bytes().rev().take_while(|&b| b == b'0').count();
+        assert_eq!(zeros as u64, trailing_zeros(1000));
+    }
+
+    //* ~2^31 trial divisions, run with `cargo test --release -- --ignored`
+    #[test]
+    #[ignore]
+    fn test_legendre_largest_u64_prime() {
+        assert_eq!(legendre(u64::MAX, u64::MAX - 58), Ok(1));
+    }
+}
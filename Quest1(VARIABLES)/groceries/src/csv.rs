@@ -0,0 +1,74 @@
+//* quotes a field only when it has to: a ',', a '"' or a line break inside it
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+//* yields the records of a CSV text with the 1-based line they start on; blank lines are skipped
+//* fields may be wrapped in double quotes, "" inside quotes is a literal quote and a quoted field
+//* can span several lines, so a record is not always a line
+#[derive(Debug, Clone)]
+pub struct CsvRecords<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+pub fn csv_records(text: &str) -> CsvRecords<'_> {
+    CsvRecords { rest: text, line: 0 }
+}
+
+impl Iterator for CsvRecords<'_> {
+    type Item = (usize, Result<Vec<String>, String>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = self.rest;
+            if rest.is_empty() {
+                return None;
+            }
+            let line = self.line + 1;
+            let mut fields = Vec::new();
+            let mut field = String::new();
+            let mut quoted = false;
+            let mut end = rest.len();
+            let mut chars = rest.char_indices().peekable();
+            while let Some((i, c)) = chars.next() {
+                let next = chars.peek().map(|&(_, n)| n);
+                match (c, quoted) {
+                    ('"', true) if next == Some('"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    ('"', true) => quoted = false,
+                    ('"', false) if field.is_empty() => quoted = true,
+                    ('\n', true) => {
+                        self.line += 1;
+                        field.push(c);
+                    }
+                    ('\n', false) => {
+                        end = i + 1;
+                        break;
+                    }
+                    //* the '\r' of a "\r\n" line ending
+                    ('\r', false) if next == Some('\n') => {}
+                    (',', false) => fields.push(std::mem::take(&mut field)),
+                    _ => field.push(c),
+                }
+            }
+            let record = &rest[..end];
+            self.rest = &rest[end..];
+            self.line += 1;
+            if quoted {
+                return Some((line, Err("unterminated quoted field".to_owned())));
+            }
+            if record.trim().is_empty() {
+                continue;
+            }
+            fields.push(field);
+            return Some((line, Ok(fields)));
+        }
+    }
+}
@@ -1,5 +1,9 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
 
+mod csv;
+mod list;
+pub use list::{Category, GroceryError, GroceryList, Item};
+
 pub fn insert(vec: &mut Vec<String>, val: String) {
     vec.push(val);
 }
@@ -31,8 +35,8 @@ pub fn insert(vec: &mut Vec<String>, val: String) {
 
 // * ═══════════════════════════════════════════════════════════════════════════════════
 
-pub fn at_index(slice: &[String], index: usize) -> &str {
-    &slice[index]
+pub fn at_index(slice: &[String], index: usize) -> Option<&str> {
+    slice.get(index).map(|s| s.as_str())
 }
 
 
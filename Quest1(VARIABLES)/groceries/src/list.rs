@@ -0,0 +1,247 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::csv::{csv_field, csv_records};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    Produce,
+    Dairy,
+    Bakery,
+    Meat,
+    Pantry,
+    Frozen,
+    Drinks,
+    Household,
+    Other,
+}
+
+impl Category {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Category::Produce => "produce",
+            Category::Dairy => "dairy",
+            Category::Bakery => "bakery",
+            Category::Meat => "meat",
+            Category::Pantry => "pantry",
+            Category::Frozen => "frozen",
+            Category::Drinks => "drinks",
+            Category::Household => "household",
+            Category::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Category {
+    type Err = GroceryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let all = [
+            Category::Produce,
+            Category::Dairy,
+            Category::Bakery,
+            Category::Meat,
+            Category::Pantry,
+            Category::Frozen,
+            Category::Drinks,
+            Category::Household,
+            Category::Other,
+        ];
+        all.into_iter()
+            .find(|c| c.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| GroceryError::UnknownCategory(s.to_owned()))
+    }
+}
+
+//* the unit is free text ("kg", "pack", ...); an empty unit means a plain count
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub name: String,
+    pub quantity: f64,
+    pub unit: String,
+    pub category: Category,
+}
+
+impl Item {
+    pub fn new(name: &str, quantity: f64, unit: &str, category: Category) -> Self {
+        Item {
+            name: name.trim().to_owned(),
+            quantity,
+            unit: unit.trim().to_owned(),
+            category,
+        }
+    }
+
+    fn same_name(&self, name: &str) -> bool {
+        self.name.to_lowercase() == name.trim().to_lowercase()
+    }
+}
+
+#[derive(Debug)]
+pub enum GroceryError {
+    //* the same item was added with two units that can't be summed ("2 kg" of rice and "1 pack" of rice)
+    UnitMismatch { name: String, existing: String, added: String },
+    InvalidQuantity(f64),
+    UnknownCategory(String),
+    Parse { line: usize, message: String },
+    Io(io::Error),
+}
+
+impl fmt::Display for GroceryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GroceryError::UnitMismatch { name, existing, added } => write!(
+                f,
+                "can't merge {:?}: it is counted in {:?} but {:?} was added",
+                name, existing, added
+            ),
+            GroceryError::InvalidQuantity(q) => write!(f, "invalid quantity {}", q),
+            GroceryError::UnknownCategory(c) => write!(f, "unknown category {:?}", c),
+            GroceryError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            GroceryError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for GroceryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GroceryError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GroceryError {
+    fn from(e: io::Error) -> Self {
+        GroceryError::Io(e)
+    }
+}
+
+//* items keep their insertion order; adding a name that is already there (ignoring case) merges the quantities
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GroceryList {
+    items: Vec<Item>,
+}
+
+const CSV_HEADER: &str = "name,quantity,unit,category";
+
+impl GroceryList {
+    pub fn new() -> Self {
+        GroceryList { items: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn add(&mut self, item: Item) -> Result<(), GroceryError> {
+        if !item.quantity.is_finite() || item.quantity <= 0.0 {
+            return Err(GroceryError::InvalidQuantity(item.quantity));
+        }
+        match self.items.iter_mut().find(|i| i.same_name(&item.name)) {
+            Some(existing) if !existing.unit.eq_ignore_ascii_case(&item.unit) => Err(GroceryError::UnitMismatch {
+                name: existing.name.clone(),
+                existing: existing.unit.clone(),
+                added: item.unit,
+            }),
+            Some(existing) => {
+                existing.quantity += item.quantity;
+                Ok(())
+            }
+            None => {
+                self.items.push(item);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Item> {
+        self.items.iter().find(|i| i.same_name(name))
+    }
+
+    pub fn at(&self, index: usize) -> Option<&Item> {
+        self.items.get(index)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Item> {
+        let index = self.items.iter().position(|i| i.same_name(name))?;
+        Some(self.items.remove(index))
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.items.iter()
+    }
+
+    pub fn in_category(&self, category: Category) -> impl Iterator<Item = &Item> {
+        self.items.iter().filter(move |i| i.category == category)
+    }
+
+    //* all-or-nothing: if one item can't be merged, `self` is left untouched
+    pub fn merge(&mut self, other: &GroceryList) -> Result<(), GroceryError> {
+        let mut merged = self.clone();
+        for item in &other.items {
+            merged.add(item.clone())?;
+        }
+        *self = merged;
+        Ok(())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        for item in &self.items {
+            out.push_str(&format!(
+                "{},{},{},{}\n",
+                csv_field(&item.name),
+                item.quantity,
+                csv_field(&item.unit),
+                item.category
+            ));
+        }
+        out
+    }
+
+    pub fn from_csv(text: &str) -> Result<Self, GroceryError> {
+        let mut list = GroceryList::new();
+        for (line_no, record) in csv_records(text) {
+            let parse_err = |message: String| GroceryError::Parse { line: line_no, message };
+            let fields = record.map_err(parse_err)?;
+            if line_no == 1 && fields.iter().map(|f| f.trim()).eq(CSV_HEADER.split(',')) {
+                continue;
+            }
+            let [name, quantity, unit, category] = <[String; 4]>::try_from(fields)
+                .map_err(|f| parse_err(format!("expected 4 fields, found {}", f.len())))?;
+            let quantity = quantity
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| parse_err(format!("invalid quantity {:?}", quantity)))?;
+            let category = category.parse::<Category>().map_err(|e| parse_err(e.to_string()))?;
+            list.add(Item::new(&name, quantity, &unit, category))
+                .map_err(|e| parse_err(e.to_string()))?;
+        }
+        Ok(list)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), GroceryError> {
+        fs::write(path, self.to_csv())?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, GroceryError> {
+        Self::from_csv(&fs::read_to_string(path)?)
+    }
+}
@@ -10,4 +10,121 @@ fn main() {
     insert(&mut groceries, String::from("nuts"));
     println!("groceries = {:?}", &groceries);
     println!("groceries[1] = {:?}", at_index(&groceries, 1));
-}
\ No newline at end of file
+
+    let mut list = GroceryList::new();
+    for item in [
+        Item::new("Yogurt", 2.0, "", Category::Dairy),
+        Item::new("flour", 1.5, "kg", Category::Pantry),
+        Item::new("yogurt", 4.0, "", Category::Dairy),
+    ] {
+        if let Err(e) = list.add(item) {
+            println!("{}", e);
+        }
+    }
+    print!("{}", list.to_csv());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> GroceryList {
+        let mut list = GroceryList::new();
+        list.add(Item::new("Milk", 2.0, "l", Category::Dairy)).unwrap();
+        list.add(Item::new("apples", 6.0, "", Category::Produce)).unwrap();
+        list.add(Item::new("Bread, whole \"grain\"", 1.0, "loaf", Category::Bakery)).unwrap();
+        list
+    }
+
+    #[test]
+    fn test_at_index() {
+        let v = vec!["a".to_string()];
+        assert_eq!(at_index(&v, 0), Some("a"));
+        assert_eq!(at_index(&v, 1), None);
+    }
+
+    #[test]
+    fn test_dedup_merges_quantities() {
+        let mut list = sample();
+        list.add(Item::new("  MILK ", 1.0, "L", Category::Dairy)).unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.get("milk").unwrap().quantity, 3.0);
+        assert!(matches!(
+            list.add(Item::new("milk", 1.0, "carton", Category::Dairy)),
+            Err(GroceryError::UnitMismatch { .. })
+        ));
+        assert!(matches!(
+            list.add(Item::new("eggs", 0.0, "", Category::Dairy)),
+            Err(GroceryError::InvalidQuantity(_))
+        ));
+    }
+
+    #[test]
+    fn test_lookups_do_not_panic() {
+        let mut list = sample();
+        assert_eq!(list.at(1).unwrap().name, "apples");
+        assert!(list.at(10).is_none());
+        assert!(list.get("pears").is_none());
+        assert_eq!(list.remove("APPLES").unwrap().quantity, 6.0);
+        assert!(list.remove("apples").is_none());
+        assert_eq!(list.in_category(Category::Dairy).count(), 1);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = sample();
+        let mut b = GroceryList::new();
+        b.add(Item::new("apples", 4.0, "", Category::Produce)).unwrap();
+        b.add(Item::new("rice", 1.0, "kg", Category::Pantry)).unwrap();
+        a.merge(&b).unwrap();
+        assert_eq!(a.len(), 4);
+        assert_eq!(a.get("apples").unwrap().quantity, 10.0);
+
+        let mut bad = GroceryList::new();
+        bad.add(Item::new("rice", 2.0, "bag", Category::Pantry)).unwrap();
+        let before = a.clone();
+        assert!(a.merge(&bad).is_err());
+        assert_eq!(a, before);
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let list = sample();
+        let csv = list.to_csv();
+        assert!(csv.contains("\"Bread, whole \"\"grain\"\"\",1,loaf,bakery"));
+        assert_eq!(GroceryList::from_csv(&csv).unwrap(), list);
+
+        let path = std::env::temp_dir().join(format!("groceries_{}.csv", std::process::id()));
+        list.save(&path).unwrap();
+        assert_eq!(GroceryList::load(&path).unwrap(), list);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(GroceryList::load(&path), Err(GroceryError::Io(_))));
+    }
+
+    #[test]
+    fn test_csv_multiline_fields() {
+        let mut list = sample();
+        list.add(Item::new("Spice mix\n(\"hot\", mild)", 2.0, "jar", Category::Other)).unwrap();
+        let csv = list.to_csv();
+        assert!(csv.contains("\"Spice mix\n(\"\"hot\"\", mild)\",2,jar,other\n"));
+        assert_eq!(GroceryList::from_csv(&csv).unwrap(), list);
+        //* "\r\n" line endings, while the line break inside the quoted name stays a bare "\n"
+        assert_eq!(GroceryList::from_csv(&csv.replace('\n', "\r\n").replace("mix\r\n", "mix\n")).unwrap(), list);
+
+        //* errors point at the line a record starts on, not at its index
+        let err = GroceryList::from_csv("\"two\nline\",1,,other\n\nmilk,x,l,dairy\n").unwrap_err();
+        assert!(matches!(err, GroceryError::Parse { line: 4, .. }));
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        let err = GroceryList::from_csv("name,quantity,unit,category\nmilk,two,l,dairy\n").unwrap_err();
+        assert!(matches!(err, GroceryError::Parse { line: 2, .. }));
+        let err = GroceryList::from_csv("milk,1,l\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: expected 4 fields, found 3");
+        let err = GroceryList::from_csv("\"milk,1,l,dairy\n").unwrap_err();
+        assert!(matches!(err, GroceryError::Parse { line: 1, .. }));
+        let err = GroceryList::from_csv("milk,1,l,toys\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1: unknown category \"toys\"");
+    }
+}
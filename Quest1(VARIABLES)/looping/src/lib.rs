@@ -0,0 +1,241 @@
+//* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
+
+use edit_distance::edit_distance;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Riddle {
+    pub question: String,
+    pub answers: Vec<String>,
+    pub hints: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum RiddleError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for RiddleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RiddleError::Io(e) => write!(f, "failed to read riddles: {}", e),
+            RiddleError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for RiddleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RiddleError::Io(e) => Some(e),
+            RiddleError::Parse { .. } => None,
+        }
+    }
+}
+
+//* one riddle per `Q:` line, followed by its `A:` answers and `H:` hints; blank lines and `#` comments are skipped
+pub fn parse_riddles(text: &str) -> Result<Vec<Riddle>, RiddleError> {
+    let mut riddles: Vec<Riddle> = Vec::new();
+    let err = |line: usize, message: &str| RiddleError::Parse { line, message: message.to_owned() };
+    for (i, raw) in text.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line
+            .split_once(':')
+            .map(|(k, v)| (k.trim().to_uppercase(), v.trim().to_owned()))
+            .ok_or_else(|| err(i + 1, "expected `Q:`, `A:` or `H:`"))?;
+        if value.is_empty() {
+            return Err(err(i + 1, "empty value"));
+        }
+        match (key.as_str(), riddles.last_mut()) {
+            ("Q", last) => {
+                if let Some(last) = last.filter(|r| r.answers.is_empty()) {
+                    return Err(err(i + 1, &format!("riddle {:?} has no answer", last.question)));
+                }
+                riddles.push(Riddle { question: value, answers: Vec::new(), hints: Vec::new() });
+            }
+            ("A", Some(riddle)) => riddle.answers.push(value),
+            ("H", Some(riddle)) => riddle.hints.push(value),
+            ("A" | "H", None) => return Err(err(i + 1, "answer or hint before the first question")),
+            _ => return Err(err(i + 1, &format!("unknown key {:?}", key))),
+        }
+    }
+    match riddles.last() {
+        None => Err(err(text.lines().count(), "no riddles found")),
+        Some(last) if last.answers.is_empty() => {
+            Err(err(text.lines().count(), &format!("riddle {:?} has no answer", last.question)))
+        }
+        Some(_) => Ok(riddles),
+    }
+}
+
+pub fn load_riddles<P: AsRef<Path>>(path: P) -> Result<Vec<Riddle>, RiddleError> {
+    let text = fs::read_to_string(path).map_err(RiddleError::Io)?;
+    parse_riddles(&text)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    //* None keeps asking until the answer is found (or stdin closes)
+    pub max_attempts: Option<u32>,
+    //* show the next hint after every `hint_after` wrong answers, 0 disables hints
+    pub hint_after: u32,
+    //* accept answers within this edit distance of a real one, 0 means exact (after normalizing);
+    //* answers shorter than `MIN_FUZZY_LEN` always need an exact match
+    pub max_distance: usize,
+}
+
+//* below this many chars (after normalizing) one typo is a different answer: any letter is one edit away from "e"
+pub const MIN_FUZZY_LEN: usize = 4;
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { max_attempts: None, hint_after: 3, max_distance: 0 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    //* accepted, but only thanks to `max_distance`
+    NearMiss,
+    Wrong,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Solved { attempts: u32 },
+    OutOfAttempts { attempts: u32 },
+    //* the input ended while this riddle was being asked
+    Abandoned { attempts: u32 },
+    //* the input ended before this riddle was reached
+    NotPlayed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub outcomes: Vec<Outcome>,
+}
+
+impl Summary {
+    pub fn solved(&self) -> usize {
+        self.outcomes.iter().filter(|o| matches!(o, Outcome::Solved { .. })).count()
+    }
+
+    pub fn total_attempts(&self) -> u32 {
+        self.outcomes
+            .iter()
+            .map(|o| match o {
+                Outcome::Solved { attempts } | Outcome::OutOfAttempts { attempts } | Outcome::Abandoned { attempts } => *attempts,
+                Outcome::NotPlayed => 0,
+            })
+            .sum()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Score: {}/{} riddles solved in {} attempts",
+            self.solved(),
+            self.outcomes.len(),
+            self.total_attempts()
+        )
+    }
+}
+
+//* lowercase with every whitespace removed, so "The  Letter E" and "the letter e" compare equal
+fn normalize(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect()
+}
+
+pub struct Game {
+    riddles: Vec<Riddle>,
+    config: Config,
+}
+
+impl Game {
+    pub fn new(riddles: Vec<Riddle>, config: Config) -> Self {
+        Game { riddles, config }
+    }
+
+    pub fn check(&self, riddle: &Riddle, guess: &str) -> Verdict {
+        let guess = normalize(guess);
+        if guess.is_empty() {
+            return Verdict::Wrong;
+        }
+        let mut verdict = Verdict::Wrong;
+        for answer in &riddle.answers {
+            let answer = normalize(answer);
+            let tolerance = if answer.chars().count() < MIN_FUZZY_LEN { 0 } else { self.config.max_distance };
+            match edit_distance(&answer, &guess) {
+                0 => return Verdict::Correct,
+                d if d <= tolerance => verdict = Verdict::NearMiss,
+                _ => {}
+            }
+        }
+        verdict
+    }
+
+    //* plays every riddle in order, reading guesses line by line from `input`; EOF ends the game cleanly
+    pub fn play<R: BufRead, W: Write>(&self, mut input: R, output: &mut W) -> io::Result<Summary> {
+        let mut outcomes = Vec::with_capacity(self.riddles.len());
+        let mut line = String::new();
+        for riddle in &self.riddles {
+            if outcomes.last().is_some_and(|o| matches!(o, Outcome::Abandoned { .. } | Outcome::NotPlayed)) {
+                outcomes.push(Outcome::NotPlayed);
+                continue;
+            }
+            let mut attempts = 0;
+            let mut hints = riddle.hints.iter();
+            let outcome = loop {
+                writeln!(output, "{}", riddle.question)?;
+                line.clear();
+                if input.read_line(&mut line)? == 0 {
+                    break Outcome::Abandoned { attempts };
+                }
+                attempts += 1;
+                match self.check(riddle, &line) {
+                    Verdict::Correct => {
+                        writeln!(output, "Number of trials: {}", attempts)?;
+                        break Outcome::Solved { attempts };
+                    }
+                    Verdict::NearMiss => {
+                        writeln!(output, "Close enough, it was {:?}. Number of trials: {}", riddle.answers[0], attempts)?;
+                        break Outcome::Solved { attempts };
+                    }
+                    Verdict::Wrong if self.config.max_attempts.is_some_and(|max| attempts >= max) => {
+                        writeln!(output, "Out of attempts, the answer was {:?}", riddle.answers[0])?;
+                        break Outcome::OutOfAttempts { attempts };
+                    }
+                    Verdict::Wrong => {
+                        if self.config.hint_after > 0 && attempts % self.config.hint_after == 0 {
+                            if let Some(hint) = hints.next() {
+                                writeln!(output, "Hint: {}", hint)?;
+                            }
+                        }
+                    }
+                }
+            };
+            outcomes.push(outcome);
+        }
+        let summary = Summary { outcomes };
+        writeln!(output, "{}", summary)?;
+        Ok(summary)
+    }
+}
+
+/*
+    * Q & A :
+    * Q1 : why does the game take a `BufRead` and a `Write` instead of using stdin/stdout directly?
+    - because then the tests can feed it a `&[u8]` as input and collect the output in a `Vec<u8>`, no terminal needed; main just passes `stdin().lock()` and `stdout()`.
+    * Q2 : how is the end of the input detected?
+    - `read_line` returns Ok(0) when there is nothing left to read (ctrl-d, or a closed pipe); the old `expect` loop kept spinning on empty input forever, now it counts as giving up.
+*/
@@ -1,20 +1,109 @@
+use looping::*;
 use std::io;
 
-fn main(){
-    let riddle: &str = "I am the beginning of the end, and the end of time and space. I am essential to creation, and I surround every place. What am I?";
-    let answer: &str = "The letter e";
-    let mut cmp = 0;
-    let mut input = String::new();
-
-    loop {
-        println!("{}", riddle);
-        input.clear();
-        io::stdin().read_line(&mut input).expect("Failed to read line");
-        cmp += 1;
-        
-        if input.trim() == answer {
-            println!("Number of trials: {}",cmp);
-            break;
-        }
+fn main() {
+    let path = std::env::args().nth(1).unwrap_or_else(|| "riddles.txt".to_owned());
+    let riddles = load_riddles(&path).unwrap_or_else(|e| {
+        eprintln!("{}, using the built-in riddle", e);
+        vec![Riddle {
+            question: "I am the beginning of the end, and the end of time and space. I am essential to creation, and I surround every place. What am I?".to_owned(),
+            answers: vec!["The letter e".to_owned()],
+            hints: Vec::new(),
+        }]
+    });
+    let config = Config { max_attempts: None, hint_after: 3, max_distance: 1 };
+    if let Err(e) = Game::new(riddles, config).play(io::stdin().lock(), &mut io::stdout()) {
+        eprintln!("{}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RIDDLES: &str = "
+# two riddles
+Q: What has keys but can't open locks?
+A: A piano
+A: piano
+H: It makes music.
+H: It has 88 keys.
+
+Q: What gets wetter the more it dries?
+A: A towel
+A: towel
+";
+
+    fn play(config: Config, input: &str) -> (Summary, String) {
+        let game = Game::new(parse_riddles(RIDDLES).unwrap(), config);
+        let mut output = Vec::new();
+        let summary = game.play(input.as_bytes(), &mut output).unwrap();
+        (summary, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_parse() {
+        let riddles = parse_riddles(RIDDLES).unwrap();
+        assert_eq!(riddles.len(), 2);
+        assert_eq!(riddles[0].answers, vec!["A piano", "piano"]);
+        assert_eq!(riddles[0].hints.len(), 2);
+        assert!(riddles[1].hints.is_empty());
+
+        assert!(matches!(parse_riddles("A: e\n"), Err(RiddleError::Parse { line: 1, .. })));
+        assert!(matches!(parse_riddles("Q: a?\nQ: b?\nA: b\n"), Err(RiddleError::Parse { line: 2, .. })));
+        assert!(matches!(parse_riddles("Q: a?\n"), Err(RiddleError::Parse { line: 1, .. })));
+        assert!(matches!(parse_riddles("Q: a?\nA: a\nX: what\n"), Err(RiddleError::Parse { line: 3, .. })));
+        assert!(matches!(parse_riddles("# nothing\n"), Err(RiddleError::Parse { .. })));
+        assert!(matches!(load_riddles("no_such_file.txt"), Err(RiddleError::Io(_))));
+    }
+
+    #[test]
+    fn test_check_normalizes_and_near_misses() {
+        let riddles = parse_riddles(RIDDLES).unwrap();
+        let exact = Game::new(riddles.clone(), Config::default());
+        assert_eq!(exact.check(&riddles[0], "  a   PIANO \n"), Verdict::Correct);
+        assert_eq!(exact.check(&riddles[0], "apiano"), Verdict::Correct);
+        assert_eq!(exact.check(&riddles[0], "pianos"), Verdict::Wrong);
+        assert_eq!(exact.check(&riddles[0], ""), Verdict::Wrong);
+
+        let lenient = Game::new(riddles.clone(), Config { max_distance: 1, ..Config::default() });
+        assert_eq!(lenient.check(&riddles[0], "pianos"), Verdict::NearMiss);
+        assert_eq!(lenient.check(&riddles[0], "pinaos"), Verdict::Wrong);
+
+        //* a one-letter answer is never matched loosely
+        let short = parse_riddles("Q: Which letter?\nA: The letter e\nA: e\n").unwrap();
+        let lenient = Game::new(short.clone(), Config { max_distance: 1, ..Config::default() });
+        assert_eq!(lenient.check(&short[0], "e"), Verdict::Correct);
+        assert_eq!(lenient.check(&short[0], "a"), Verdict::Wrong);
+        assert_eq!(lenient.check(&short[0], "x"), Verdict::Wrong);
+        assert_eq!(lenient.check(&short[0], "the leter e"), Verdict::NearMiss);
+    }
+
+    #[test]
+    fn test_full_game() {
+        let (summary, output) = play(Config::default(), "guitar\npiano\ntowel\n");
+        assert_eq!(summary.outcomes, vec![Outcome::Solved { attempts: 2 }, Outcome::Solved { attempts: 1 }]);
+        assert!(output.contains("Number of trials: 2"));
+        assert!(output.ends_with("Score: 2/2 riddles solved in 3 attempts\n"));
+    }
+
+    #[test]
+    fn test_hints_and_attempt_limit() {
+        let config = Config { max_attempts: Some(4), hint_after: 2, max_distance: 0 };
+        let (summary, output) = play(config, "a\nb\nc\nd\ntowel\n");
+        assert_eq!(summary.outcomes, vec![Outcome::OutOfAttempts { attempts: 4 }, Outcome::Solved { attempts: 1 }]);
+        assert!(output.contains("Hint: It makes music."));
+        assert!(!output.contains("Hint: It has 88 keys."));
+        assert!(output.contains("Out of attempts, the answer was \"A piano\""));
+        assert_eq!(summary.solved(), 1);
+    }
+
+    #[test]
+    fn test_eof() {
+        let (summary, output) = play(Config::default(), "guitar\n");
+        assert_eq!(summary.outcomes, vec![Outcome::Abandoned { attempts: 1 }, Outcome::NotPlayed]);
+        assert!(output.ends_with("Score: 0/2 riddles solved in 1 attempts\n"));
+        let (summary, _) = play(Config::default(), "");
+        assert_eq!(summary.outcomes, vec![Outcome::Abandoned { attempts: 0 }, Outcome::NotPlayed]);
     }
 }
@@ -0,0 +1,138 @@
+use std::fmt;
+
+//* 32 x 32 tiles of 8-byte values are 8KB each, so a source tile and a destination tile fit together in a 32KB L1 cache
+pub const DEFAULT_BLOCK: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    //* the buffer length is not rows * cols
+    Length { expected: usize, found: usize },
+    NotSquare { rows: usize, cols: usize },
+    //* rows * cols overflows a usize, or that many elements can't be allocated
+    TooLarge { rows: usize, cols: usize },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShapeError::Length { expected, found } => {
+                write!(f, "expected {} elements, found {}", expected, found)
+            }
+            ShapeError::NotSquare { rows, cols } => {
+                write!(f, "a {}x{} matrix can't be transposed in place", rows, cols)
+            }
+            ShapeError::TooLarge { rows, cols } => write!(f, "a {}x{} matrix is too large", rows, cols),
+        }
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+//* rows x cols matrix stored row-major: element (r, c) lives at data[r * cols + c]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Copy> FlatMatrix<T> {
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, ShapeError> {
+        let len = rows.checked_mul(cols).ok_or(ShapeError::TooLarge { rows, cols })?;
+        if data.len() != len {
+            return Err(ShapeError::Length { expected: len, found: data.len() });
+        }
+        Ok(FlatMatrix { rows, cols, data })
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> Result<Self, ShapeError> {
+        let too_large = ShapeError::TooLarge { rows, cols };
+        let len = rows.checked_mul(cols).ok_or(too_large)?;
+        let mut data = Vec::new();
+        data.try_reserve_exact(len).map_err(|_| too_large)?;
+        for r in 0..rows {
+            for c in 0..cols {
+                data.push(f(r, c));
+            }
+        }
+        Ok(FlatMatrix { rows, cols, data })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.data.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    //* writes the destination in order but reads the source column by column, `cols` elements apart: on a large
+    //* matrix every read lands on a different cache line
+    pub fn transposed(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for c in 0..self.cols {
+            for r in 0..self.rows {
+                data.push(self.data[r * self.cols + c]);
+            }
+        }
+        FlatMatrix { rows: self.cols, cols: self.rows, data }
+    }
+
+    //* same result as `transposed`, but it walks the matrix one block x block tile at a time so both tiles stay in cache.
+    //* The writes jump around the destination, so it is filled with `T::default()` first
+    pub fn transposed_blocked(&self, block: usize) -> Self
+    where
+        T: Default,
+    {
+        let block = block.max(1);
+        let (rows, cols) = (self.rows, self.cols);
+        let mut data = vec![T::default(); self.data.len()];
+        for r0 in (0..rows).step_by(block) {
+            for c0 in (0..cols).step_by(block) {
+                for r in r0..(r0 + block).min(rows) {
+                    for c in c0..(c0 + block).min(cols) {
+                        data[c * rows + r] = self.data[r * cols + c];
+                    }
+                }
+            }
+        }
+        FlatMatrix { rows: cols, cols: rows, data }
+    }
+
+    //* swaps (r, c) with (c, r) above the diagonal, no extra buffer; only square matrices keep their shape
+    pub fn transpose_in_place(&mut self) -> Result<(), ShapeError> {
+        self.transpose_in_place_blocked(self.rows.max(1))
+    }
+
+    //* the in-place swap done tile by tile: diagonal tiles are transposed on themselves, the others are swapped with their mirror tile
+    pub fn transpose_in_place_blocked(&mut self, block: usize) -> Result<(), ShapeError> {
+        if self.rows != self.cols {
+            return Err(ShapeError::NotSquare { rows: self.rows, cols: self.cols });
+        }
+        let n = self.rows;
+        let block = block.max(1);
+        for r0 in (0..n).step_by(block) {
+            for c0 in (r0..n).step_by(block) {
+                for r in r0..(r0 + block).min(n) {
+                    let start = if r0 == c0 { r + 1 } else { c0 };
+                    for c in start..(c0 + block).min(n) {
+                        self.data.swap(r * n + c, c * n + r);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
 
+mod flat;
+pub use flat::{FlatMatrix, ShapeError, DEFAULT_BLOCK};
+
 #[derive(Debug , PartialEq , Eq)]
 pub struct Matrix(pub (i32, i32), pub (i32, i32));
 
@@ -4,4 +4,66 @@ fn main() {
     let matrix = Matrix((1, 3), (4, 5));
     println!("Original matrix {:?}", matrix);
     println!("Transpose matrix {:?}", transpose(matrix));
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(rows: usize, cols: usize) -> FlatMatrix<u64> {
+        FlatMatrix::from_fn(rows, cols, |r, c| (r * cols + c) as u64).unwrap()
+    }
+
+    #[test]
+    fn test_fixed_2x2() {
+        assert_eq!(transpose(Matrix((1, 3), (4, 5))), Matrix((1, 4), (3, 5)));
+    }
+
+    #[test]
+    fn test_rectangular() {
+        let m = FlatMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let t = m.transposed();
+        assert_eq!((t.rows(), t.cols()), (3, 2));
+        assert_eq!(t.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(t.get(2, 1), Some(&6));
+        assert_eq!(t.get(2, 2), None);
+        assert_eq!(t.transposed(), m);
+        assert_eq!(
+            FlatMatrix::new(2, 2, vec![1, 2, 3]),
+            Err(ShapeError::Length { expected: 4, found: 3 })
+        );
+        assert_eq!(FlatMatrix::new(usize::MAX, 2, vec![0u8]), Err(ShapeError::TooLarge { rows: usize::MAX, cols: 2 }));
+        assert_eq!(FlatMatrix::from_fn(2, usize::MAX, |_, _| 0u8), Err(ShapeError::TooLarge { rows: 2, cols: usize::MAX }));
+        assert_eq!(FlatMatrix::from_fn(usize::MAX / 2, 1, |_, _| 0u64), Err(ShapeError::TooLarge { rows: usize::MAX / 2, cols: 1 }));
+    }
+
+    #[test]
+    fn test_blocked_matches_naive() {
+        for (rows, cols) in [(0, 0), (1, 7), (7, 1), (31, 33), (64, 64), (100, 37), (129, 200)] {
+            let m = numbered(rows, cols);
+            let naive = m.transposed();
+            for block in [1, 3, 8, DEFAULT_BLOCK, 1000] {
+                assert_eq!(m.transposed_blocked(block), naive, "{}x{} block {}", rows, cols, block);
+            }
+        }
+    }
+
+    #[test]
+    fn test_in_place_matches_naive() {
+        for n in [0, 1, 2, 5, 32, 33, 97] {
+            let m = numbered(n, n);
+            let naive = m.transposed();
+            for block in [1, 4, DEFAULT_BLOCK] {
+                let mut inplace = m.clone();
+                inplace.transpose_in_place_blocked(block).unwrap();
+                assert_eq!(inplace, naive, "{}x{} block {}", n, n, block);
+            }
+            let mut inplace = m.clone();
+            inplace.transpose_in_place().unwrap();
+            assert_eq!(inplace, naive);
+        }
+        assert_eq!(
+            numbered(2, 3).transpose_in_place(),
+            Err(ShapeError::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::iter::FusedIterator;
+
+mod tables;
+pub use tables::UNICODE_VERSION;
+
+//* the Grapheme_Cluster_Break property of a char, plus the two extra properties the rules look at
+//* (Extended_Pictographic for emoji and the Indic_Conjunct_Break consonants)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gcb {
+    Other,
+    CR,
+    LF,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+    InCbConsonant,
+}
+
+const HANGUL_BASE: u32 = 0xAC00;
+const HANGUL_LAST: u32 = 0xD7A3;
+const HANGUL_T_COUNT: u32 = 28;
+
+pub fn grapheme_break(c: char) -> Gcb {
+    let cp = c as u32;
+    //* precomposed hangul syllables: every 28th one has no trailing jamo (LV), the 27 after it have one (LVT)
+    if (HANGUL_BASE..=HANGUL_LAST).contains(&cp) {
+        return if (cp - HANGUL_BASE).is_multiple_of(HANGUL_T_COUNT) { Gcb::LV } else { Gcb::LVT };
+    }
+    let table = tables::GRAPHEME_BREAK;
+    match find_range(cp, table.len(), |i| (table[i].0, table[i].1)) {
+        Some(i) => table[i].2,
+        None => Gcb::Other,
+    }
+}
+
+fn is_incb_extend(c: char) -> bool {
+    let table = tables::INCB_EXTEND;
+    find_range(c as u32, table.len(), |i| table[i]).is_some()
+}
+
+//* binary search over sorted, non-overlapping inclusive ranges; `range(i)` gives the bounds of the i-th one
+fn find_range<F: Fn(usize) -> (u32, u32)>(cp: u32, len: usize, range: F) -> Option<usize> {
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let (start, end) = range(mid);
+        if end < cp {
+            lo = mid + 1;
+        } else if start > cp {
+            hi = mid;
+        } else {
+            return Some(mid);
+        }
+    }
+    None
+}
+
+fn is_incb_linker(c: char) -> bool {
+    tables::INCB_LINKER.contains(&(c as u32))
+}
+
+//* what the rules that look further back than one char (GB9c, GB11, GB12/13) need to remember about the current cluster
+#[derive(Debug, Clone, Copy, Default)]
+struct Context {
+    //* GB11: an Extended_Pictographic followed by Extend* (and then maybe a ZWJ)
+    pictographic: bool,
+    pictographic_zwj: bool,
+    //* GB9c: an InCB consonant followed by InCB Extend/Linker chars, and whether a linker was among them
+    consonant: bool,
+    linked: bool,
+    //* GB12/13: how many regional indicators are in a row right before the current position
+    regional_indicators: usize,
+}
+
+impl Context {
+    fn push(&mut self, c: char, gcb: Gcb) {
+        self.pictographic_zwj = self.pictographic && gcb == Gcb::Zwj;
+        self.pictographic = gcb == Gcb::ExtendedPictographic || (self.pictographic && gcb == Gcb::Extend);
+
+        if gcb == Gcb::InCbConsonant {
+            self.consonant = true;
+            self.linked = false;
+        } else if self.consonant && is_incb_linker(c) {
+            self.linked = true;
+        } else if !(self.consonant && is_incb_extend(c)) {
+            self.consonant = false;
+            self.linked = false;
+        }
+
+        self.regional_indicators = match gcb {
+            Gcb::RegionalIndicator => self.regional_indicators + 1,
+            _ => 0,
+        };
+    }
+
+    //* the UAX #29 rules GB3..GB999, in order; true means a cluster boundary sits between `prev` and `next`
+    fn is_boundary(&self, prev: Gcb, next: Gcb) -> bool {
+        use Gcb::*;
+        match (prev, next) {
+            (CR, LF) => false,
+            (Control | CR | LF, _) | (_, Control | CR | LF) => true,
+            (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => false,
+            (_, Extend | Zwj | SpacingMark) | (Prepend, _) => false,
+            (_, InCbConsonant) if self.consonant && self.linked => false,
+            (Zwj, ExtendedPictographic) if self.pictographic_zwj => false,
+            (RegionalIndicator, RegionalIndicator) => self.regional_indicators.is_multiple_of(2),
+            _ => true,
+        }
+    }
+}
+
+//* finds cluster boundaries one char at a time, for text that doesn't come as a single &str
+#[derive(Debug, Clone, Default)]
+pub struct GraphemeBreaker {
+    prev: Option<Gcb>,
+    context: Context,
+}
+
+impl GraphemeBreaker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    //* true when `c` starts a new cluster, which the very first char always does
+    pub fn push(&mut self, c: char) -> bool {
+        let gcb = grapheme_break(c);
+        let boundary = match self.prev {
+            Some(prev) => self.context.is_boundary(prev, gcb),
+            None => true,
+        };
+        if boundary {
+            self.context = Context::default();
+        }
+        self.context.push(c, gcb);
+        self.prev = Some(gcb);
+        boundary
+    }
+}
+
+//* iterator over the extended grapheme clusters of a string, each one a slice of the input
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+pub fn graphemes(input: &str) -> Graphemes<'_> {
+    Graphemes { rest: input }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut breaker = GraphemeBreaker::new();
+        breaker.push(first);
+
+        let end = chars.find(|&(_, c)| breaker.push(c)).map_or(self.rest.len(), |(i, _)| i);
+        let (cluster, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(cluster)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(!self.rest.is_empty()), Some(self.rest.len()))
+    }
+}
+
+impl FusedIterator for Graphemes<'_> {}
@@ -1,4 +1,21 @@
+mod grapheme;
+pub use grapheme::{grapheme_break, graphemes, Gcb, GraphemeBreaker, Graphemes, UNICODE_VERSION};
+
+//* reverses user-perceived characters: "é" written as e + U+0301, flags, ZWJ emoji and hangul jamo stay intact
 pub fn rev_str(input: &str) -> String {
+    let clusters: Vec<&str> = graphemes(input).collect();
+    clusters.into_iter().rev().collect()
+}
+
+//* the old behaviour, reversing code points one by one; combining marks end up on the wrong letter
+pub fn rev_chars(input: &str) -> String {
     input.chars().rev().collect()
 }
 
+/*
+    * Q1 : why isn't `chars().rev()` enough?
+    - a `char` is one unicode scalar value, but what a reader sees as one character can be several of them: "é" can be `e` + U+0301 (combining acute), 🇲🇦 is two regional indicators, 👨‍👩‍👧 is three emoji glued by U+200D (zero width joiner) and 한 can be three hangul jamo.
+        reversing chars moves the accent onto the previous letter, turns 🇲🇦 into 🇦🇲 and breaks the family apart. UAX #29 defines "extended grapheme clusters", the unit that should stay together, with a list of rules (GB3..GB999) about where a boundary may or may not go.
+    * Q2 : where does the data come from?
+    - src/grapheme/tables.rs is generated from the unicode character database and checked in, so the crate needs no download at build time; when unicode gets a new version, the tables are regenerated.
+*/
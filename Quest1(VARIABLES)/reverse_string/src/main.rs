@@ -6,4 +6,51 @@ fn main() {
     println!("{}", rev_str("I have a nice car!"));
     println!("{}", rev_str("How old are You"));
     println!("{}", rev_str("ex: this is an example água"));
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(rev_str("Hello, world!"), "!dlrow ,olleH");
+        assert_eq!(rev_str(""), "");
+    }
+
+    #[test]
+    fn test_combining_marks() {
+        assert_eq!(rev_str("cafe\u{301}"), "e\u{301}fac");
+        assert_eq!(rev_chars("cafe\u{301}"), "\u{301}efac");
+        assert_eq!(rev_str("água"), "augá");
+    }
+
+    #[test]
+    fn test_emoji() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(graphemes(family).count(), 1);
+        assert_eq!(rev_str(&format!("a{}b", family)), format!("b{}a", family));
+        assert_eq!(rev_str("🇲🇦🇫🇷"), "🇫🇷🇲🇦");
+        assert_eq!(graphemes("🇲🇦🇫🇷🇪").collect::<Vec<_>>(), vec!["🇲🇦", "🇫🇷", "🇪"]);
+        assert_eq!(rev_str("👍🏽!"), "!👍🏽");
+    }
+
+    #[test]
+    fn test_hangul_jamo() {
+        let han = "\u{1112}\u{1161}\u{11AB}";
+        assert_eq!(graphemes(han).count(), 1);
+        assert_eq!(rev_str(&format!("{}\u{AC00}", han)), format!("\u{AC00}{}", han));
+        assert_eq!(grapheme_break('\u{AC00}'), Gcb::LV);
+        assert_eq!(grapheme_break('\u{AC01}'), Gcb::LVT);
+    }
+
+    #[test]
+    fn test_segmentation_rules() {
+        assert_eq!(graphemes("a\r\nb").collect::<Vec<_>>(), vec!["a", "\r\n", "b"]);
+        //* devanagari conjunct क्ष (ka + virama + ssa) is one cluster since unicode 15.1
+        assert_eq!(graphemes("\u{915}\u{94D}\u{937}").count(), 1);
+        //* a prepend char sticks to what follows it
+        assert_eq!(graphemes("\u{600}1 ").collect::<Vec<_>>(), vec!["\u{600}1", " "]);
+        let text = "ex: this is an example água 🇲🇦";
+        assert_eq!(graphemes(text).collect::<String>(), text);
+    }
+}
@@ -1,5 +1,8 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
 
+mod policy;
+pub use policy::{apply, apply_float, ArithError, Op, Output, OverflowPolicy, PolicyFloat, PolicyInt};
+
 pub fn sum(a: u8, b: u8) -> u8 {
     a + b
 }
@@ -30,4 +33,6 @@ pub fn rem(a: f32, b: f32) -> f32 {
      -> i64 : 4 values in 256-bit register
      *=> you see the importance of using the right data type for your application
      *=> using smaller data types can lead to significant performance improvements, especially in applications that require processing large amounts of data, such as machine learning inference.
+     *=> the flip side of small types is that they overflow sooner: 200u8 + 100 panics in debug and silently gives 44 in release.
+        `apply` makes that choice explicit with an OverflowPolicy instead of leaving it to the build profile.
 */
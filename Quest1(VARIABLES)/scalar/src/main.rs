@@ -0,0 +1,69 @@
+use scalar::*;
+
+fn main() {
+    println!("sum(100, 100) = {}", sum(100, 100));
+    for policy in [
+        OverflowPolicy::Checked,
+        OverflowPolicy::Wrapping,
+        OverflowPolicy::Saturating,
+        OverflowPolicy::Widening,
+    ] {
+        println!("200u8 + 100 with {:?}: {:?}", policy, apply(Op::Add, 200u8, 100, policy));
+    }
+    println!("1.0 / 0.0: {:?}", apply_float(Op::Div, 1.0f32, 0.0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policies_on_overflow() {
+        assert_eq!(apply(Op::Add, 200u8, 100, OverflowPolicy::Checked), Err(ArithError::Overflow(Op::Add)));
+        assert_eq!(apply(Op::Add, 200u8, 100, OverflowPolicy::Wrapping), Ok(Output::Same(44)));
+        assert_eq!(apply(Op::Add, 200u8, 100, OverflowPolicy::Saturating), Ok(Output::Same(255)));
+        assert_eq!(apply(Op::Add, 200u8, 100, OverflowPolicy::Widening), Ok(Output::Wide(300u16)));
+
+        assert_eq!(apply(Op::Sub, -32768i16, 1, OverflowPolicy::Saturating), Ok(Output::Same(i16::MIN)));
+        assert_eq!(apply(Op::Mul, -128i8, -1, OverflowPolicy::Wrapping), Ok(Output::Same(-128)));
+        assert_eq!(apply(Op::Mul, -128i8, -1, OverflowPolicy::Widening).unwrap().wide(), 128i16);
+        assert_eq!(apply(Op::Div, i32::MIN, -1, OverflowPolicy::Saturating), Ok(Output::Same(i32::MAX)));
+        assert_eq!(apply(Op::Rem, i64::MIN, -1, OverflowPolicy::Saturating), Ok(Output::Same(0)));
+        assert_eq!(apply(Op::Rem, i64::MIN, -1, OverflowPolicy::Checked), Err(ArithError::Overflow(Op::Rem)));
+        assert_eq!(apply(Op::Mul, u128::MAX, 2, OverflowPolicy::Widening), Err(ArithError::Overflow(Op::Mul)));
+        assert_eq!(apply(Op::Sub, 0u64, 1, OverflowPolicy::Widening), Err(ArithError::Overflow(Op::Sub)));
+        assert_eq!(apply(Op::Sub, 0u8, 1, OverflowPolicy::Widening), Err(ArithError::Overflow(Op::Sub)));
+        assert_eq!(apply(Op::Sub, 1u8, 1, OverflowPolicy::Widening), Ok(Output::Wide(0u16)));
+        assert_eq!(apply(Op::Div, i128::MIN, -1, OverflowPolicy::Widening), Err(ArithError::Overflow(Op::Div)));
+        assert_eq!(apply(Op::Sub, 2usize, 3, OverflowPolicy::Checked), Err(ArithError::Overflow(Op::Sub)));
+        assert_eq!(apply(Op::Add, usize::MAX, 1, OverflowPolicy::Saturating), Ok(Output::Same(usize::MAX)));
+        assert_eq!(apply(Op::Mul, usize::MAX, 2, OverflowPolicy::Widening), Ok(Output::Wide(usize::MAX as u128 * 2)));
+        assert_eq!(apply(Op::Sub, isize::MIN, 1, OverflowPolicy::Wrapping), Ok(Output::Same(isize::MAX)));
+        assert_eq!(apply(Op::Div, isize::MIN, -1, OverflowPolicy::Widening).unwrap().wide(), -(isize::MIN as i128));
+        assert_eq!(apply(Op::Add, 3usize, 4, OverflowPolicy::Checked).unwrap().wide(), 7u128);
+    }
+
+    #[test]
+    fn test_policies_agree_without_overflow() {
+        for policy in [
+            OverflowPolicy::Checked,
+            OverflowPolicy::Wrapping,
+            OverflowPolicy::Saturating,
+            OverflowPolicy::Widening,
+        ] {
+            assert_eq!(apply(Op::Sub, 7i16, 10, policy).unwrap().wide(), -3);
+            assert_eq!(apply(Op::Rem, -7i8, 3, policy).unwrap().wide(), -1);
+            assert_eq!(apply(Op::Div, 1000u32, 7, policy).unwrap().wide(), 142);
+            assert_eq!(apply(Op::Div, 1u8, 0, policy), Err(ArithError::DivisionByZero));
+        }
+    }
+
+    #[test]
+    fn test_floats() {
+        assert_eq!(apply_float(Op::Div, 7.0f32, 2.0), Ok(3.5));
+        assert_eq!(apply_float(Op::Rem, 7.0f64, 0.0), Err(ArithError::DivisionByZero));
+        assert_eq!(apply_float(Op::Mul, f32::MAX, 2.0), Err(ArithError::NonFinite(f64::INFINITY)));
+        assert!(matches!(apply_float(Op::Sub, f64::INFINITY, 1.0), Err(ArithError::NonFinite(_))));
+        assert!(matches!(apply_float(Op::Add, f64::NAN, 1.0), Err(ArithError::NonFinite(v)) if v.is_nan()));
+    }
+}
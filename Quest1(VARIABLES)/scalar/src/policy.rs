@@ -0,0 +1,193 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+//* what to do when the exact result does not fit in the operand type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    //* report an ArithError::Overflow
+    Checked,
+    //* keep the low bits (two's complement), what release builds do by default
+    Wrapping,
+    //* clamp to MIN/MAX
+    Saturating,
+    //* compute in the next wider type (i8 -> i16, ..., i64 -> i128, u64 -> u128, isize/usize -> i128/u128).
+    //* Exact for every operation on types up to 64 bits, except unsigned Sub: the wide type is unsigned
+    //* too, so 0u8 - 1 is still an Overflow. i128/u128 have nothing wider and overflow like Checked
+    Widening,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArithError {
+    Overflow(Op),
+    DivisionByZero,
+    //* a float operation produced inf or NaN
+    NonFinite(f64),
+}
+
+impl fmt::Display for ArithError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArithError::Overflow(op) => write!(f, "{:?} overflowed", op),
+            ArithError::DivisionByZero => write!(f, "attempt to divide by zero"),
+            ArithError::NonFinite(v) => write!(f, "result is not finite: {}", v),
+        }
+    }
+}
+
+impl std::error::Error for ArithError {}
+
+//* the result is in the operand type except under OverflowPolicy::Widening
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output<T, W> {
+    Same(T),
+    Wide(W),
+}
+
+impl<T: PolicyInt> Output<T, T::Wide> {
+    pub fn wide(self) -> T::Wide {
+        match self {
+            Output::Same(v) => v.widen(),
+            Output::Wide(v) => v,
+        }
+    }
+}
+
+pub trait PolicyInt: Copy {
+    //* i128/u128 have nothing wider, they widen into themselves and can still overflow;
+    //* usize/isize go to u128/i128 since their width depends on the target
+    type Wide: Copy;
+
+    //* lossless, but usize has no `Into<u128>` so this can't be an `Into` bound
+    fn widen(self) -> Self::Wide;
+    fn is_zero(self) -> bool;
+    fn checked(op: Op, a: Self, b: Self) -> Option<Self>;
+    fn wrapping(op: Op, a: Self, b: Self) -> Self;
+    fn saturating(op: Op, a: Self, b: Self) -> Self;
+    fn widening(op: Op, a: Self, b: Self) -> Option<Self::Wide>;
+}
+
+macro_rules! impl_policy_int {
+    ($($t:ty => $w:ty),*) => {$(
+        impl PolicyInt for $t {
+            type Wide = $w;
+
+            fn widen(self) -> $w {
+                self as $w
+            }
+
+            fn is_zero(self) -> bool {
+                self == 0
+            }
+
+            fn checked(op: Op, a: Self, b: Self) -> Option<Self> {
+                match op {
+                    Op::Add => a.checked_add(b),
+                    Op::Sub => a.checked_sub(b),
+                    Op::Mul => a.checked_mul(b),
+                    Op::Div => a.checked_div(b),
+                    Op::Rem => a.checked_rem(b),
+                }
+            }
+
+            fn wrapping(op: Op, a: Self, b: Self) -> Self {
+                match op {
+                    Op::Add => a.wrapping_add(b),
+                    Op::Sub => a.wrapping_sub(b),
+                    Op::Mul => a.wrapping_mul(b),
+                    Op::Div => a.wrapping_div(b),
+                    Op::Rem => a.wrapping_rem(b),
+                }
+            }
+
+            fn saturating(op: Op, a: Self, b: Self) -> Self {
+                match op {
+                    Op::Add => a.saturating_add(b),
+                    Op::Sub => a.saturating_sub(b),
+                    Op::Mul => a.saturating_mul(b),
+                    Op::Div => a.saturating_div(b),
+                    //* MIN % -1 is mathematically 0, so there is nothing to clamp
+                    Op::Rem => a.wrapping_rem(b),
+                }
+            }
+
+            fn widening(op: Op, a: Self, b: Self) -> Option<$w> {
+                <$w as PolicyInt>::checked(op, a.widen(), b.widen())
+            }
+        }
+    )*};
+}
+
+impl_policy_int!(
+    u8 => u16, u16 => u32, u32 => u64, u64 => u128, u128 => u128, usize => u128,
+    i8 => i16, i16 => i32, i32 => i64, i64 => i128, i128 => i128, isize => i128
+);
+
+//* applies `op` under `policy`; dividing by zero is an error whatever the policy, since no policy can give it a value
+pub fn apply<T: PolicyInt>(
+    op: Op,
+    a: T,
+    b: T,
+    policy: OverflowPolicy,
+) -> Result<Output<T, T::Wide>, ArithError> {
+    if matches!(op, Op::Div | Op::Rem) && b.is_zero() {
+        return Err(ArithError::DivisionByZero);
+    }
+    match policy {
+        OverflowPolicy::Checked => T::checked(op, a, b).map(Output::Same).ok_or(ArithError::Overflow(op)),
+        OverflowPolicy::Wrapping => Ok(Output::Same(T::wrapping(op, a, b))),
+        OverflowPolicy::Saturating => Ok(Output::Same(T::saturating(op, a, b))),
+        OverflowPolicy::Widening => T::widening(op, a, b).map(Output::Wide).ok_or(ArithError::Overflow(op)),
+    }
+}
+
+pub trait PolicyFloat: Copy + Into<f64> + PartialEq {
+    const ZERO: Self;
+
+    fn compute(op: Op, a: Self, b: Self) -> Self;
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_policy_float {
+    ($($t:ty),*) => {$(
+        impl PolicyFloat for $t {
+            const ZERO: Self = 0.0;
+
+            fn compute(op: Op, a: Self, b: Self) -> Self {
+                match op {
+                    Op::Add => a + b,
+                    Op::Sub => a - b,
+                    Op::Mul => a * b,
+                    Op::Div => a / b,
+                    Op::Rem => a % b,
+                }
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+        }
+    )*};
+}
+
+impl_policy_float!(f32, f64);
+
+//* floats never wrap, they turn into inf/NaN instead; this reports that (and x / 0, x % 0) as an error
+pub fn apply_float<F: PolicyFloat>(op: Op, a: F, b: F) -> Result<F, ArithError> {
+    if matches!(op, Op::Div | Op::Rem) && b == F::ZERO {
+        return Err(ArithError::DivisionByZero);
+    }
+    let result = F::compute(op, a, b);
+    if result.is_finite() {
+        Ok(result)
+    } else {
+        Err(ArithError::NonFinite(result.into()))
+    }
+}
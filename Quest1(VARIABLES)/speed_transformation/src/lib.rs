@@ -1,5 +1,8 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
 
+mod speed;
+pub use speed::{convert_query, Speed, SpeedError, SpeedUnit, MACH_SEA_LEVEL_MS};
+
 pub fn km_per_hour_to_meters_per_second(km_h: f64) -> f64 {
     (km_h * 1000.0) / (60.0 * 60.0)
 }
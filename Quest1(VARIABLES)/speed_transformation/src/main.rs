@@ -0,0 +1,73 @@
+use speed_transformation::*;
+
+fn main() {
+    println!("100 km/h = {} m/s", km_per_hour_to_meters_per_second(100.0));
+    for query in ["88 mph in km/h", "12.5 kn to m/s", "1 Mach in mph", "3 furlongs in mph"] {
+        match convert_query(query) {
+            Ok(speed) => println!("{} = {:.2}", query, speed),
+            Err(e) => println!("{}: {}", query, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert!(close(Speed::new(36.0, SpeedUnit::KilometersPerHour).meters_per_second(), 10.0));
+        assert!(close(Speed::new(1.0, SpeedUnit::Knots).to(SpeedUnit::KilometersPerHour).value, 1.852));
+        assert!(close(Speed::new(60.0, SpeedUnit::MilesPerHour).to(SpeedUnit::FeetPerSecond).value, 88.0));
+        assert!(close(Speed::new(1.0, SpeedUnit::Mach).meters_per_second(), MACH_SEA_LEVEL_MS));
+        assert!(close(
+            km_per_hour_to_meters_per_second(90.0),
+            Speed::new(90.0, SpeedUnit::KilometersPerHour).meters_per_second()
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_all_pairs() {
+        for from in SpeedUnit::ALL {
+            for to in SpeedUnit::ALL {
+                let speed = Speed::new(123.456, from);
+                let back = speed.to(to).to(from);
+                assert!(close(back.value, speed.value), "{} -> {}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!("88 mph".parse(), Ok(Speed::new(88.0, SpeedUnit::MilesPerHour)));
+        assert_eq!("12.5 kn".parse(), Ok(Speed::new(12.5, SpeedUnit::Knots)));
+        assert_eq!("12.5kn".parse(), Ok(Speed::new(12.5, SpeedUnit::Knots)));
+        assert_eq!(" -3 M/S ".parse(), Ok(Speed::new(-3.0, SpeedUnit::MetersPerSecond)));
+        assert_eq!("1e3 km/h".parse(), Ok(Speed::new(1000.0, SpeedUnit::KilometersPerHour)));
+        assert_eq!("2 ft/s".parse(), Ok(Speed::new(2.0, SpeedUnit::FeetPerSecond)));
+        assert_eq!("88".parse::<Speed>(), Err(SpeedError::MissingUnit));
+        assert_eq!("fast mph".parse::<Speed>(), Err(SpeedError::InvalidNumber("".to_owned())));
+        assert_eq!("3 furlongs".parse::<Speed>(), Err(SpeedError::UnknownUnit("furlongs".to_owned())));
+
+        assert_eq!(Speed::new(88.0, SpeedUnit::MilesPerHour).to_string(), "88 mph");
+        assert_eq!(format!("{:.1}", Speed::new(2.0 / 3.0, SpeedUnit::Mach)), "0.7 Mach");
+        for unit in SpeedUnit::ALL {
+            let speed = Speed::new(42.5, unit);
+            assert_eq!(speed.to_string().parse(), Ok(speed));
+        }
+    }
+
+    #[test]
+    fn test_queries() {
+        let speed = convert_query("88 mph in km/h").unwrap();
+        assert_eq!(speed.unit, SpeedUnit::KilometersPerHour);
+        assert_eq!(format!("{:.2}", speed), "141.62 km/h");
+        assert_eq!(format!("{:.3}", convert_query("12.5 kn TO m/s").unwrap()), "6.431 m/s");
+        assert_eq!(convert_query("88 mph in warp"), Err(SpeedError::UnknownUnit("warp".to_owned())));
+        assert!(matches!(convert_query("88 mph"), Err(SpeedError::MalformedQuery(_))));
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt;
+use std::str::FromStr;
+
+//* speed of sound in dry air at sea level in the ISA standard atmosphere (15 °C)
+pub const MACH_SEA_LEVEL_MS: f64 = 340.294;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpeedUnit {
+    MetersPerSecond,
+    KilometersPerHour,
+    MilesPerHour,
+    Knots,
+    FeetPerSecond,
+    Mach,
+}
+
+impl SpeedUnit {
+    pub const ALL: [SpeedUnit; 6] = [
+        SpeedUnit::MetersPerSecond,
+        SpeedUnit::KilometersPerHour,
+        SpeedUnit::MilesPerHour,
+        SpeedUnit::Knots,
+        SpeedUnit::FeetPerSecond,
+        SpeedUnit::Mach,
+    ];
+
+    //* how many m/s one of this unit is
+    pub fn meters_per_second(&self) -> f64 {
+        match self {
+            SpeedUnit::MetersPerSecond => 1.0,
+            SpeedUnit::KilometersPerHour => 1000.0 / 3600.0,
+            SpeedUnit::MilesPerHour => 1609.344 / 3600.0,
+            SpeedUnit::Knots => 1852.0 / 3600.0,
+            SpeedUnit::FeetPerSecond => 0.3048,
+            SpeedUnit::Mach => MACH_SEA_LEVEL_MS,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SpeedUnit::MetersPerSecond => "m/s",
+            SpeedUnit::KilometersPerHour => "km/h",
+            SpeedUnit::MilesPerHour => "mph",
+            SpeedUnit::Knots => "kn",
+            SpeedUnit::FeetPerSecond => "ft/s",
+            SpeedUnit::Mach => "Mach",
+        }
+    }
+}
+
+impl fmt::Display for SpeedUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl FromStr for SpeedUnit {
+    type Err = SpeedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit = match s.trim().to_lowercase().as_str() {
+            "m/s" | "mps" | "ms" | "meters per second" | "metres per second" => SpeedUnit::MetersPerSecond,
+            "km/h" | "kmh" | "kph" | "km/hr" | "kilometers per hour" | "kilometres per hour" => SpeedUnit::KilometersPerHour,
+            "mph" | "mi/h" | "miles per hour" => SpeedUnit::MilesPerHour,
+            "kn" | "kt" | "kts" | "knot" | "knots" => SpeedUnit::Knots,
+            "ft/s" | "fps" | "feet per second" => SpeedUnit::FeetPerSecond,
+            "mach" | "ma" => SpeedUnit::Mach,
+            _ => return Err(SpeedError::UnknownUnit(s.trim().to_owned())),
+        };
+        Ok(unit)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpeedError {
+    UnknownUnit(String),
+    InvalidNumber(String),
+    MissingUnit,
+    //* a conversion query that is not of the form "<speed> in <unit>"
+    MalformedQuery(String),
+}
+
+impl fmt::Display for SpeedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpeedError::UnknownUnit(u) => write!(f, "unknown speed unit {:?}", u),
+            SpeedError::InvalidNumber(n) => write!(f, "invalid number {:?}", n),
+            SpeedError::MissingUnit => write!(f, "missing speed unit"),
+            SpeedError::MalformedQuery(q) => write!(f, "expected \"<speed> in <unit>\", got {:?}", q),
+        }
+    }
+}
+
+impl std::error::Error for SpeedError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Speed {
+    pub value: f64,
+    pub unit: SpeedUnit,
+}
+
+impl Speed {
+    pub fn new(value: f64, unit: SpeedUnit) -> Self {
+        Speed { value, unit }
+    }
+
+    pub fn to(&self, unit: SpeedUnit) -> Speed {
+        Speed::new(self.value * self.unit.meters_per_second() / unit.meters_per_second(), unit)
+    }
+
+    pub fn meters_per_second(&self) -> f64 {
+        self.to(SpeedUnit::MetersPerSecond).value
+    }
+}
+
+//* "88 mph", "12.5 kn", "12.5kn", "-3 m/s"; the number comes first and the unit is everything after it
+impl FromStr for Speed {
+    type Err = SpeedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (number, unit) = s.split_at(number_len(s));
+        let value = number
+            .parse::<f64>()
+            .map_err(|_| SpeedError::InvalidNumber(number.to_owned()))?;
+        let unit = unit.trim();
+        if unit.is_empty() {
+            return Err(SpeedError::MissingUnit);
+        }
+        Ok(Speed::new(value, unit.parse()?))
+    }
+}
+
+//* length of the leading float literal: sign, digits and dots, then an exponent only if digits follow the `e`
+fn number_len(s: &str) -> usize {
+    let b = s.as_bytes();
+    let digits_from = |mut i: usize| {
+        while i < b.len() && (b[i].is_ascii_digit() || b[i] == b'.') {
+            i += 1;
+        }
+        i
+    };
+    let mut end = digits_from(usize::from(matches!(b.first(), Some(b'+' | b'-'))));
+    if matches!(b.get(end), Some(b'e' | b'E')) {
+        let mut exp = end + 1;
+        if matches!(b.get(exp), Some(b'+' | b'-')) {
+            exp += 1;
+        }
+        if b.get(exp).is_some_and(u8::is_ascii_digit) {
+            end = digits_from(exp);
+        }
+    }
+    end
+}
+
+//* honours the precision of the format string: format!("{:.1}", speed) gives "141.6 km/h"
+impl fmt::Display for Speed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*} {}", p, self.value, self.unit),
+            None => write!(f, "{} {}", self.value, self.unit),
+        }
+    }
+}
+
+//* answers queries like "88 mph in km/h" or "12.5 kn to m/s"
+pub fn convert_query(query: &str) -> Result<Speed, SpeedError> {
+    let lower = query.to_ascii_lowercase();
+    let at = [" in ", " to "]
+        .iter()
+        .filter_map(|sep| lower.rfind(sep).map(|i| (i, sep.len())))
+        .max()
+        .ok_or_else(|| SpeedError::MalformedQuery(query.to_owned()))?;
+    let speed: Speed = query[..at.0].parse()?;
+    let unit: SpeedUnit = query[at.0 + at.1..].parse()?;
+    Ok(speed.to(unit))
+}
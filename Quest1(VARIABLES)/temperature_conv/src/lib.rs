@@ -1,5 +1,8 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
 
+mod temperature;
+pub use temperature::{ConversionTable, Scale, Temperature, TemperatureError, MAX_TABLE_ROWS};
+
 // Converts Fahrenheit to Celsius
 pub fn fahrenheit_to_celsius(f: f64) -> f64 {
     (f - 32.0) / (9.0 / 5.0)
@@ -0,0 +1,119 @@
+use temperature_conv::*;
+
+fn main() {
+    println!("100°F = {:.2}°C", fahrenheit_to_celsius(100.0));
+    match "300 K".parse::<Temperature>() {
+        Ok(t) => println!("{} = {:.2}", t, t.to(Scale::Fahrenheit)),
+        Err(e) => println!("{}", e),
+    }
+    if let Ok(table) = ConversionTable::new(Scale::Celsius, -40.0, 100.0, 20.0, &Scale::ALL) {
+        print!("{}", table.to_text(2));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_known_points() {
+        let boiling = Temperature::new(100.0, Scale::Celsius).unwrap();
+        let expected = [
+            (Scale::Fahrenheit, 212.0),
+            (Scale::Kelvin, 373.15),
+            (Scale::Rankine, 671.67),
+            (Scale::Reaumur, 80.0),
+            (Scale::Delisle, 0.0),
+        ];
+        for (scale, value) in expected {
+            assert!(close(boiling.to(scale).value(), value), "{}", scale);
+        }
+        let minus_forty = Temperature::new(-40.0, Scale::Fahrenheit).unwrap();
+        assert!(close(minus_forty.to(Scale::Celsius).value(), -40.0));
+        assert!(close(celsius_to_fahrenheit(-40.0), -40.0));
+    }
+
+    #[test]
+    fn test_absolute_zero() {
+        assert!(Temperature::new(0.0, Scale::Kelvin).is_ok());
+        assert!(Temperature::new(-273.15, Scale::Celsius).is_ok());
+        assert!(Temperature::new(-459.67, Scale::Fahrenheit).is_ok());
+        assert!(Temperature::new(559.725, Scale::Delisle).is_ok());
+        assert_eq!(
+            Temperature::new(-300.0, Scale::Celsius),
+            Err(TemperatureError::BelowAbsoluteZero { value: -300.0, scale: Scale::Celsius })
+        );
+        assert!(Temperature::new(-0.1, Scale::Rankine).is_err());
+        assert!(Temperature::new(600.0, Scale::Delisle).is_err());
+        assert_eq!(Temperature::new(f64::NAN, Scale::Kelvin), Err(TemperatureError::NotFinite));
+    }
+
+    #[test]
+    fn test_round_trips() {
+        for from in Scale::ALL {
+            for to in Scale::ALL {
+                let t = Temperature::new(1234.5, Scale::Kelvin).unwrap().to(from);
+                assert!(close(t.to(to).to(from).value(), t.value()), "{} -> {}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!("-40°F".parse(), Temperature::new(-40.0, Scale::Fahrenheit));
+        assert_eq!("300 K".parse(), Temperature::new(300.0, Scale::Kelvin));
+        assert_eq!(" 25 ° c".parse(), Temperature::new(25.0, Scale::Celsius));
+        assert_eq!("80Ré".parse(), Temperature::new(80.0, Scale::Reaumur));
+        assert_eq!("12 °De".parse(), Temperature::new(12.0, Scale::Delisle));
+        assert_eq!("-5 K".parse::<Temperature>(), Temperature::new(-5.0, Scale::Kelvin));
+        assert!(matches!("-5 K".parse::<Temperature>(), Err(TemperatureError::BelowAbsoluteZero { .. })));
+        assert_eq!("12 X".parse::<Temperature>(), Err(TemperatureError::UnknownScale("X".to_owned())));
+        assert_eq!("hot °C".parse::<Temperature>(), Err(TemperatureError::InvalidNumber("".to_owned())));
+
+        assert_eq!(Temperature::new(-40.0, Scale::Fahrenheit).unwrap().to_string(), "-40°F");
+        assert_eq!(Temperature::new(300.0, Scale::Kelvin).unwrap().to_string(), "300 K");
+        let body = Temperature::new(37.0, Scale::Celsius).unwrap();
+        assert_eq!(format!("{:.1}", body.to(Scale::Fahrenheit)), "98.6°F");
+        for scale in Scale::ALL {
+            let t = Temperature::new(300.0, Scale::Kelvin).unwrap().to(scale);
+            assert_eq!(t.to_string().parse(), Ok(t));
+        }
+    }
+
+    #[test]
+    fn test_tables() {
+        let table = ConversionTable::new(Scale::Celsius, 0.0, 100.0, 50.0, &[Scale::Fahrenheit, Scale::Kelvin]).unwrap();
+        assert_eq!(table.to_csv(1), "°C,°F,K\n0.0,32.0,273.1\n50.0,122.0,323.1\n100.0,212.0,373.1\n");
+        assert_eq!(
+            table.to_text(2),
+            "    °C      °F       K\n  0.00   32.00  273.15\n 50.00  122.00  323.15\n100.00  212.00  373.15\n"
+        );
+        let fine = ConversionTable::new(Scale::Kelvin, 0.0, 1.0, 0.1, &[]).unwrap();
+        assert_eq!(fine.rows.len(), 11);
+        let down = ConversionTable::new(Scale::Fahrenheit, 212.0, 32.0, -90.0, &[Scale::Celsius]).unwrap();
+        let expected = [[212.0, 100.0], [122.0, 50.0], [32.0, 0.0]];
+        assert_eq!(down.rows.len(), expected.len());
+        for (row, want) in down.rows.iter().zip(expected) {
+            assert!(close(row[0], want[0]) && close(row[1], want[1]));
+        }
+
+        assert!(matches!(ConversionTable::new(Scale::Celsius, 0.0, 10.0, -1.0, &[]), Err(TemperatureError::InvalidStep(_))));
+        assert!(matches!(ConversionTable::new(Scale::Celsius, 0.0, 10.0, 0.0, &[]), Err(TemperatureError::InvalidStep(_))));
+        assert!(matches!(ConversionTable::new(Scale::Celsius, -300.0, 10.0, 1.0, &[]), Err(TemperatureError::BelowAbsoluteZero { .. })));
+        assert!(matches!(ConversionTable::new(Scale::Kelvin, 0.0, 1e9, 1.0, &[]), Err(TemperatureError::TooManyRows(_))));
+        assert_eq!(
+            ConversionTable::new(Scale::Celsius, 0.0, 1e300, 1.0, &[]),
+            Err(TemperatureError::TooManyRows(usize::MAX))
+        );
+        let last = (MAX_TABLE_ROWS - 1) as f64;
+        assert_eq!(ConversionTable::new(Scale::Kelvin, 0.0, last, 1.0, &[]).map(|t| t.rows.len()), Ok(MAX_TABLE_ROWS));
+        assert_eq!(
+            ConversionTable::new(Scale::Kelvin, 0.0, last + 1.0, 1.0, &[]),
+            Err(TemperatureError::TooManyRows(MAX_TABLE_ROWS + 1))
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scale {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Rankine,
+    Reaumur,
+    Delisle,
+}
+
+impl Scale {
+    pub const ALL: [Scale; 6] = [
+        Scale::Celsius,
+        Scale::Fahrenheit,
+        Scale::Kelvin,
+        Scale::Rankine,
+        Scale::Reaumur,
+        Scale::Delisle,
+    ];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Scale::Celsius => "°C",
+            Scale::Fahrenheit => "°F",
+            Scale::Kelvin => "K",
+            Scale::Rankine => "°R",
+            Scale::Reaumur => "°Ré",
+            Scale::Delisle => "°De",
+        }
+    }
+
+    fn kelvin_from_value(self, value: f64) -> f64 {
+        match self {
+            Scale::Celsius => value + 273.15,
+            Scale::Fahrenheit => (value + 459.67) * 5.0 / 9.0,
+            Scale::Kelvin => value,
+            Scale::Rankine => value * 5.0 / 9.0,
+            Scale::Reaumur => value * 5.0 / 4.0 + 273.15,
+            //* delisle counts downward from the boiling point of water, so hotter means a smaller number
+            Scale::Delisle => 373.15 - value * 2.0 / 3.0,
+        }
+    }
+
+    fn value_from_kelvin(self, kelvin: f64) -> f64 {
+        match self {
+            Scale::Celsius => kelvin - 273.15,
+            Scale::Fahrenheit => kelvin * 9.0 / 5.0 - 459.67,
+            Scale::Kelvin => kelvin,
+            Scale::Rankine => kelvin * 9.0 / 5.0,
+            Scale::Reaumur => (kelvin - 273.15) * 4.0 / 5.0,
+            Scale::Delisle => (373.15 - kelvin) * 3.0 / 2.0,
+        }
+    }
+}
+
+impl fmt::Display for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.symbol())
+    }
+}
+
+impl FromStr for Scale {
+    type Err = TemperatureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        let name = name.strip_prefix('°').unwrap_or(name).trim_start();
+        let scale = match name.to_lowercase().as_str() {
+            "c" | "celsius" => Scale::Celsius,
+            "f" | "fahrenheit" => Scale::Fahrenheit,
+            "k" | "kelvin" => Scale::Kelvin,
+            "r" | "ra" | "rankine" => Scale::Rankine,
+            "ré" | "re" | "réaumur" | "reaumur" => Scale::Reaumur,
+            "de" | "d" | "delisle" => Scale::Delisle,
+            _ => return Err(TemperatureError::UnknownScale(s.trim().to_owned())),
+        };
+        Ok(scale)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemperatureError {
+    BelowAbsoluteZero { value: f64, scale: Scale },
+    NotFinite,
+    UnknownScale(String),
+    InvalidNumber(String),
+    //* the step of a table is zero, not finite, or points away from the end of the range
+    InvalidStep(f64),
+    TooManyRows(usize),
+}
+
+impl fmt::Display for TemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TemperatureError::BelowAbsoluteZero { value, scale } => {
+                write!(f, "{}{} is below absolute zero", value, scale)
+            }
+            TemperatureError::NotFinite => write!(f, "temperature must be a finite number"),
+            TemperatureError::UnknownScale(s) => write!(f, "unknown temperature scale {:?}", s),
+            TemperatureError::InvalidNumber(n) => write!(f, "invalid number {:?}", n),
+            TemperatureError::InvalidStep(step) => write!(f, "invalid table step {}", step),
+            TemperatureError::TooManyRows(n) => {
+                write!(f, "table would have {} rows, the limit is {}", n, MAX_TABLE_ROWS)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemperatureError {}
+
+//* a temperature that is known to be at or above absolute zero; the fields are private so that can't be broken afterwards
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature {
+    value: f64,
+    scale: Scale,
+}
+
+//* rounding in the conversions can put 0 K at -1e-13 K, that must not count as below absolute zero
+const KELVIN_EPSILON: f64 = 1e-9;
+
+impl Temperature {
+    pub fn new(value: f64, scale: Scale) -> Result<Self, TemperatureError> {
+        if !value.is_finite() {
+            return Err(TemperatureError::NotFinite);
+        }
+        if scale.kelvin_from_value(value) < -KELVIN_EPSILON {
+            return Err(TemperatureError::BelowAbsoluteZero { value, scale });
+        }
+        Ok(Temperature { value, scale })
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn scale(&self) -> Scale {
+        self.scale
+    }
+
+    pub fn kelvin(&self) -> f64 {
+        self.scale.kelvin_from_value(self.value).max(0.0)
+    }
+
+    pub fn to(&self, scale: Scale) -> Temperature {
+        if scale == self.scale {
+            return *self;
+        }
+        Temperature { value: scale.value_from_kelvin(self.kelvin()), scale }
+    }
+}
+
+//* "-40°F", "300 K", "25 °C", "80 Ré"
+impl FromStr for Temperature {
+    type Err = TemperatureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && matches!(c, '-' | '+'))))
+            .map(|(i, _)| i)
+            .unwrap_or(s.len());
+        let (number, scale) = s.split_at(split);
+        let value = number
+            .parse::<f64>()
+            .map_err(|_| TemperatureError::InvalidNumber(number.to_owned()))?;
+        Temperature::new(value, scale.parse()?)
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        //* SI writes kelvin with a space and no degree sign, the other scales stick to the number
+        let sep = if self.scale == Scale::Kelvin { " " } else { "" };
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}{}{}", p, self.value, sep, self.scale),
+            None => write!(f, "{}{}{}", self.value, sep, self.scale),
+        }
+    }
+}
+
+pub const MAX_TABLE_ROWS: usize = 100_000;
+
+//* one row per step of the source scale, one column per requested scale (the source scale first)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionTable {
+    pub scales: Vec<Scale>,
+    pub rows: Vec<Vec<f64>>,
+}
+
+impl ConversionTable {
+    //* `start..=end` in `from`, every `step`; the end is included when the range is a whole number of steps
+    pub fn new(from: Scale, start: f64, end: f64, step: f64, to: &[Scale]) -> Result<Self, TemperatureError> {
+        if !step.is_finite() || step == 0.0 || (end - start) * step < 0.0 {
+            return Err(TemperatureError::InvalidStep(step));
+        }
+        Temperature::new(start, from)?;
+        Temperature::new(end, from)?;
+        //* a tiny tolerance so 0.1-sized steps still land on the end despite floating point error
+        let steps = ((end - start) / step + 1e-9).floor();
+        //* checked before the cast, which saturates for a range like 0..1e300
+        if steps >= MAX_TABLE_ROWS as f64 {
+            return Err(TemperatureError::TooManyRows((steps as usize).saturating_add(1)));
+        }
+        let count = steps as usize + 1;
+        let mut scales = vec![from];
+        scales.extend(to.iter().copied().filter(|&s| s != from));
+        let rows = (0..count)
+            .map(|i| {
+                let t = Temperature::new(start + i as f64 * step, from)?;
+                Ok(scales.iter().map(|&s| t.to(s).value()).collect())
+            })
+            .collect::<Result<_, TemperatureError>>()?;
+        Ok(ConversionTable { scales, rows })
+    }
+
+    //* right-aligned columns, `precision` digits after the point
+    pub fn to_text(&self, precision: usize) -> String {
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|v| format!("{:.*}", precision, v)).collect())
+            .collect();
+        let widths: Vec<usize> = self
+            .scales
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                cells
+                    .iter()
+                    .map(|row| row[i].chars().count())
+                    .chain(std::iter::once(s.symbol().chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let line = |items: Vec<String>| {
+            items
+                .iter()
+                .zip(&widths)
+                .map(|(item, &w)| format!("{:>w$}", item, w = w))
+                .collect::<Vec<_>>()
+                .join("  ")
+        };
+        let mut out = line(self.scales.iter().map(|s| s.symbol().to_owned()).collect());
+        out.push('\n');
+        for row in cells {
+            out.push_str(&line(row));
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn to_csv(&self, precision: usize) -> String {
+        let mut out = self.scales.iter().map(|s| s.symbol()).collect::<Vec<_>>().join(",");
+        out.push('\n');
+        for row in &self.rows {
+            let cells: Vec<String> = row.iter().map(|v| format!("{:.*}", precision, v)).collect();
+            out.push_str(&cells.join(","));
+            out.push('\n');
+        }
+        out
+    }
+}
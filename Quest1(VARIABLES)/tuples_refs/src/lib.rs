@@ -1,3 +1,8 @@
+mod csv;
+mod roster;
+pub use roster::{Roster, RosterError, SortKey};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Student(pub u32, pub String, pub String);
 
 pub fn id(student: &Student) -> u32 {
@@ -10,4 +15,4 @@ pub fn first_name(student: &Student) -> &str {
 
 pub fn last_name(student: &Student) -> &str {
     &student.2
-}
\ No newline at end of file
+}
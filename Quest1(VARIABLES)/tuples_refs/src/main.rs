@@ -6,3 +6,100 @@ fn main() {
     println!("Student's last name: {}", last_name(&student));
     println!("Student's id: {}", id(&student));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn student(id: u32, first: &str, last: &str) -> Student {
+        Student(id, first.to_owned(), last.to_owned())
+    }
+
+    fn sample() -> Roster {
+        let mut roster = Roster::new();
+        roster.add(student(3, "Pedro", "Domingos")).unwrap();
+        roster.add(student(1, "ana", "Silva")).unwrap();
+        roster.add(student(2, "Paula", "dias")).unwrap();
+        roster
+    }
+
+    #[test]
+    fn test_index_and_duplicates() {
+        let mut roster = sample();
+        assert_eq!(roster.get(1).map(first_name), Some("ana"));
+        assert_eq!(roster.add(student(1, "Other", "Person")), Err(RosterError::DuplicateId(1)));
+        assert_eq!(roster.remove(3).map(|s| id(&s)), Some(3));
+        assert_eq!(roster.get(2).map(last_name), Some("dias"));
+        assert!(roster.get(3).is_none());
+        assert_eq!(roster.len(), 2);
+
+        let list = [student(1, "a", "b"), student(2, "c", "d"), student(1, "e", "f"), student(1, "g", "h")];
+        assert_eq!(Roster::duplicate_ids(&list), vec![1]);
+    }
+
+    #[test]
+    fn test_prefix_and_sorting() {
+        let roster = sample();
+        let found: Vec<u32> = roster.find_by_name_prefix("p").map(id).collect();
+        assert_eq!(found, vec![3, 2]);
+        let found: Vec<u32> = roster.find_by_name_prefix("SIL").map(id).collect();
+        assert_eq!(found, vec![1]);
+
+        let ids = |key| roster.sorted_by(key).into_iter().map(id).collect::<Vec<_>>();
+        assert_eq!(ids(SortKey::LastName), vec![2, 3, 1]);
+        assert_eq!(ids(SortKey::FirstName), vec![1, 2, 3]);
+        assert_eq!(ids(SortKey::Id), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut roster = sample();
+        roster.add(student(7, "Mary, Jr.", "O\"Neil")).unwrap();
+        roster.add(student(8, "Two\nLines", "X")).unwrap();
+        let csv = roster.to_csv();
+        let (back, errors) = Roster::from_csv(&csv);
+        assert!(errors.is_empty());
+        assert_eq!(back.students().collect::<Vec<_>>(), roster.students().collect::<Vec<_>>());
+
+        let text = "id,first_name,last_name\n1,Ana,Silva\nx,Bad,Id\n2,Only\n1,Dup,Id\n\n4,\"Rui\nJo\u{e3}o\",Costa\n5, ,Empty\n3,\"Open,Quote\n6,Eva,Lopes\n";
+        let (roster, errors) = Roster::from_csv(text);
+        assert_eq!(roster.students().map(id).collect::<Vec<_>>(), vec![1, 4]);
+        assert_eq!(
+            errors,
+            vec![
+                RosterError::Parse { line: 3, message: "invalid id \"x\"".to_owned() },
+                RosterError::Parse { line: 4, message: "expected 3 fields, found 2".to_owned() },
+                RosterError::Parse { line: 5, message: "duplicate student id 1".to_owned() },
+                RosterError::Parse { line: 9, message: "student 5 has an empty name".to_owned() },
+                RosterError::Parse { line: 10, message: "unterminated quoted field".to_owned() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_names() {
+        //* `add` and `from_csv` follow the same rules: an empty or blank first or last name is rejected
+        let mut roster = Roster::new();
+        assert_eq!(roster.add(student(1, "", "Silva")), Err(RosterError::EmptyName(1)));
+        assert_eq!(roster.add(student(2, "Ana", " \t")), Err(RosterError::EmptyName(2)));
+        assert!(roster.is_empty());
+        let (from_csv, errors) = Roster::from_csv("1,,Silva\n2,Ana, \t\n3,\"\",Lopes\n");
+        assert!(from_csv.is_empty());
+        assert_eq!(errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(), [
+            "line 1: student 1 has an empty name",
+            "line 2: student 2 has an empty name",
+            "line 3: student 3 has an empty name",
+        ]);
+
+        //* both keep a one-letter name and trim the same way
+        roster.add(student(4, "A", "B")).unwrap();
+        roster.add(student(5, " Ana\t", "  Silva ")).unwrap();
+        assert_eq!(roster.get(5), Some(&student(5, "Ana", "Silva")));
+        let (from_csv, errors) = Roster::from_csv("4,A,B\n5, Ana\t,  Silva \n");
+        assert!(errors.is_empty());
+        assert_eq!(from_csv.students().collect::<Vec<_>>(), roster.students().collect::<Vec<_>>());
+        let (back, errors) = Roster::from_csv(&roster.to_csv());
+        assert!(errors.is_empty());
+        assert_eq!(back.students().collect::<Vec<_>>(), roster.students().collect::<Vec<_>>());
+    }
+}
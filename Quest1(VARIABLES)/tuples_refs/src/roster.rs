@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::csv::{csv_field, csv_records};
+use crate::{first_name, id, last_name, Student};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    //* last name, then first name, then id
+    LastName,
+    //* first name, then last name, then id
+    FirstName,
+    Id,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RosterError {
+    DuplicateId(u32),
+    //* a first or last name that is empty or only whitespace
+    EmptyName(u32),
+    //* one bad line of a CSV import; `line` is 1-based
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for RosterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RosterError::DuplicateId(id) => write!(f, "duplicate student id {}", id),
+            RosterError::EmptyName(id) => write!(f, "student {} has an empty name", id),
+            RosterError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for RosterError {}
+
+//* students in insertion order plus an id -> position index; ids are unique
+#[derive(Default)]
+pub struct Roster {
+    students: Vec<Student>,
+    by_id: HashMap<u32, usize>,
+}
+
+const CSV_HEADER: &str = "id,first_name,last_name";
+
+impl Roster {
+    pub fn new() -> Self {
+        Roster { students: Vec::new(), by_id: HashMap::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.students.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.students.is_empty()
+    }
+
+    //* names are stored trimmed; `from_csv` goes through here too, so both accept the same names
+    pub fn add(&mut self, student: Student) -> Result<(), RosterError> {
+        let Student(student_id, first, last) = student;
+        let (first, last) = (first.trim(), last.trim());
+        if first.is_empty() || last.is_empty() {
+            return Err(RosterError::EmptyName(student_id));
+        }
+        if self.by_id.contains_key(&student_id) {
+            return Err(RosterError::DuplicateId(student_id));
+        }
+        self.by_id.insert(student_id, self.students.len());
+        self.students.push(Student(student_id, first.to_owned(), last.to_owned()));
+        Ok(())
+    }
+
+    pub fn remove(&mut self, student_id: u32) -> Option<Student> {
+        let index = self.by_id.remove(&student_id)?;
+        let student = self.students.remove(index);
+        for position in self.by_id.values_mut() {
+            if *position > index {
+                *position -= 1;
+            }
+        }
+        Some(student)
+    }
+
+    pub fn get(&self, student_id: u32) -> Option<&Student> {
+        self.by_id.get(&student_id).map(|&i| &self.students[i])
+    }
+
+    pub fn students(&self) -> impl Iterator<Item = &Student> {
+        self.students.iter()
+    }
+
+    //* case-insensitive prefix match on the first or the last name
+    pub fn find_by_name_prefix<'a>(&'a self, prefix: &str) -> impl Iterator<Item = &'a Student> {
+        let prefix = prefix.to_lowercase();
+        self.students.iter().filter(move |s| {
+            first_name(s).to_lowercase().starts_with(&prefix) || last_name(s).to_lowercase().starts_with(&prefix)
+        })
+    }
+
+    //* the roster itself keeps insertion order, this returns a sorted view
+    pub fn sorted_by(&self, key: SortKey) -> Vec<&Student> {
+        let mut sorted: Vec<&Student> = self.students.iter().collect();
+        let lower = |s: &str| s.to_lowercase();
+        match key {
+            SortKey::LastName => sorted.sort_by_key(|s| (lower(last_name(s)), lower(first_name(s)), id(s))),
+            SortKey::FirstName => sorted.sort_by_key(|s| (lower(first_name(s)), lower(last_name(s)), id(s))),
+            SortKey::Id => sorted.sort_by_key(|s| id(s)),
+        }
+        sorted
+    }
+
+    //* every id that appears more than once in `students`, each reported once, in order of first repeat
+    pub fn duplicate_ids(students: &[Student]) -> Vec<u32> {
+        let mut seen = HashMap::new();
+        let mut duplicates = Vec::new();
+        for student in students {
+            let count = seen.entry(id(student)).or_insert(0);
+            *count += 1;
+            if *count == 2 {
+                duplicates.push(id(student));
+            }
+        }
+        duplicates
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        for student in &self.students {
+            out.push_str(&format!(
+                "{},{},{}\n",
+                id(student),
+                csv_field(first_name(student)),
+                csv_field(last_name(student))
+            ));
+        }
+        out
+    }
+
+    //* imports every good record and reports the bad ones (malformed fields, duplicated ids, empty names) instead of stopping at the first
+    pub fn from_csv(text: &str) -> (Roster, Vec<RosterError>) {
+        let mut roster = Roster::new();
+        let mut errors = Vec::new();
+        for (line_no, record) in csv_records(text) {
+            if line_no == 1 && record.as_ref().is_ok_and(|f| f.iter().map(|f| f.trim()).eq(CSV_HEADER.split(','))) {
+                continue;
+            }
+            let result = record
+                .and_then(parse_record)
+                .map_err(|message| RosterError::Parse { line: line_no, message })
+                .and_then(|student| {
+                    roster.add(student).map_err(|e| RosterError::Parse { line: line_no, message: e.to_string() })
+                });
+            if let Err(e) = result {
+                errors.push(e);
+            }
+        }
+        (roster, errors)
+    }
+}
+
+fn parse_record(fields: Vec<String>) -> Result<Student, String> {
+    let [student_id, first, last] = <[String; 3]>::try_from(fields)
+        .map_err(|f| format!("expected 3 fields, found {}", f.len()))?;
+    let student_id = student_id
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| format!("invalid id {:?}", student_id))?;
+    Ok(Student(student_id, first, last))
+}
@@ -1,31 +1,85 @@
-pub fn arrange_phrase(phrase: &str) -> String {
-    let mut words: Vec<&str> = phrase.split_whitespace().collect();
-
-    // Sort the words by the first digit found in each slice.
-    // section 1 :
-    words.sort_by_key(|word| {
-        println!("{}", word);
-        word.chars()
-            .find_map(|c| c.to_digit(10))
-            .unwrap_or(0)
-    });
-
-    // Pre-allocate the result string to minimize reallocations.
-    let mut result = String::with_capacity(phrase.len());
-
-    for (i, word) in words.iter().enumerate() {
-        if i > 0 {
-            result.push(' ');
-        }
-        // Filter out digits and push directly to the pre-allocated string.
-        for c in word.chars() {
-            if !c.is_ascii_digit() {
-                result.push(c);
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrangeError {
+    MissingPosition { word: String },
+    //* digits in two separate places, like "a1b2"
+    MultiplePositions { word: String },
+    //* position 0, or higher than the number of words
+    OutOfRange { word: String, position: usize },
+    DuplicatePosition { word: String, position: usize },
+    //* scramble_phrase can't embed a position into a word that already has digits, it would not round-trip
+    WordHasDigits { word: String },
+}
+
+impl fmt::Display for ArrangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArrangeError::MissingPosition { word } => write!(f, "{:?} has no position", word),
+            ArrangeError::MultiplePositions { word } => write!(f, "{:?} has more than one position", word),
+            ArrangeError::OutOfRange { word, position } => {
+                write!(f, "{:?} has position {}, which is out of range", word, position)
+            }
+            ArrangeError::DuplicatePosition { word, position } => {
+                write!(f, "{:?} repeats position {}", word, position)
             }
+            ArrangeError::WordHasDigits { word } => write!(f, "{:?} already contains digits", word),
         }
     }
+}
+
+impl std::error::Error for ArrangeError {}
+
+//* splits "is12" into ("is", 12); the position is the single run of ascii digits, wherever it sits in the word
+fn split_position(word: &str) -> Result<(String, usize), ArrangeError> {
+    let Some(start) = word.find(|c: char| c.is_ascii_digit()) else {
+        return Err(ArrangeError::MissingPosition { word: word.to_owned() });
+    };
+    let end = word[start..].find(|c: char| !c.is_ascii_digit()).map_or(word.len(), |i| start + i);
+    if word[end..].contains(|c: char| c.is_ascii_digit()) {
+        return Err(ArrangeError::MultiplePositions { word: word.to_owned() });
+    }
+    //* a position too big for usize is out of range anyway
+    let position = word[start..end].parse().unwrap_or(usize::MAX);
+    Ok((format!("{}{}", &word[..start], &word[end..]), position))
+}
 
-    result
+//* words carry their 1-based position: "is2 Thi1s T4est 3a" -> "This is a Test"
+pub fn arrange_phrase(phrase: &str) -> Result<String, ArrangeError> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let mut slots: Vec<Option<String>> = vec![None; words.len()];
+
+    for word in &words {
+        let (clean, position) = split_position(word)?;
+        if position == 0 || position > words.len() {
+            return Err(ArrangeError::OutOfRange { word: word.to_string(), position });
+        }
+        let slot = &mut slots[position - 1];
+        if slot.is_some() {
+            return Err(ArrangeError::DuplicatePosition { word: word.to_string(), position });
+        }
+        *slot = Some(clean);
+    }
+
+    //* n words with n distinct positions in 1..=n fill every slot
+    Ok(slots.into_iter().flatten().collect::<Vec<_>>().join(" "))
+}
+
+//* the inverse of arrange_phrase: embeds each word's position at a random place inside it and shuffles the words
+pub fn scramble_phrase<R: Rng + ?Sized>(phrase: &str, rng: &mut R) -> Result<String, ArrangeError> {
+    let mut words = Vec::new();
+    for (i, word) in phrase.split_whitespace().enumerate() {
+        if word.contains(|c: char| c.is_ascii_digit()) {
+            return Err(ArrangeError::WordHasDigits { word: word.to_owned() });
+        }
+        let boundaries: Vec<usize> = word.char_indices().map(|(b, _)| b).chain([word.len()]).collect();
+        let at = boundaries[rng.gen_range(0..boundaries.len())];
+        words.push(format!("{}{}{}", &word[..at], i + 1, &word[at..]));
+    }
+    words.shuffle(rng);
+    Ok(words.join(" "))
 }
 
 /*
@@ -37,6 +91,7 @@ pub fn arrange_phrase(phrase: &str) -> String {
             - For each word, it iterates through the characters to find the first digit.
             - If a digit is found, it uses that digit as the key for sorting. -> what we mean by `key` is that the sorting algorithm will use the value of the first digit found in the word to determine the order of the words in the sorted list. and this part is done by the `find_map` method which returns the first digit found in the word as a `u32` value.
             - If no digit is found, it defaults to 0, meaning such words will be sorted before any words with digits.
+            (that was the first version; now the whole run of digits is the position, so `word12` goes to slot 12, and a word without digits is an error instead of silently going first)
             and the 10 is for the base of the number system, which is used to convert the character to a digit. since we are dealing with decimal digits (0-9), we use base 10.
     * Q2 : what is Char<'_> type in Rust?
     - A2 : `Char<'_>` is a type in Rust that represents a single Unicode scalar value. It is a primitive type that can hold any valid Unicode character, including letters, digits, symbols, and emojis. The `'_` part is a lifetime annotation that indicates that the character can live for any lifetime, meaning it is not tied to a specific scope or duration. This allows the `Char` type to be used flexibly in various contexts without worrying about ownership or borrowing issues related to lifetimes. In the context of the code snippet, `c` is a variable of type `Char<'_>`, which means it can hold any single Unicode character found in the word being processed. what do we mean by having a limited lifetime in Rust?
@@ -1,5 +1,81 @@
 use arrange_it::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 fn main() {
-    println!("{}", arrange_phrase("is2 Thi1s T4est 3a"));
-}
\ No newline at end of file
+    println!("{:?}", arrange_phrase("is2 Thi1s T4est 3a"));
+    println!("{:?}", arrange_phrase("is2 Thi1s T2est"));
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let scrambled = scramble_phrase("the quick brown fox jumps over the lazy dog again and again", &mut rng);
+    println!("{:?}", scrambled);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_arrange() {
+        assert_eq!(arrange_phrase("is2 Thi1s T4est 3a"), Ok("This is a Test".to_owned()));
+        assert_eq!(arrange_phrase("4of Fo1r pe6ople g3ood th5e the2"), Ok("For the good of the people".to_owned()));
+        assert_eq!(arrange_phrase(""), Ok(String::new()));
+        let long: Vec<String> = (1..=12).rev().map(|i| format!("w{}", i)).collect();
+        assert_eq!(arrange_phrase(&long.join(" ")), Ok(["w"; 12].join(" ")));
+    }
+
+    #[test]
+    fn test_errors_name_the_word() {
+        assert_eq!(
+            arrange_phrase("is2 This"),
+            Err(ArrangeError::MissingPosition { word: "This".to_owned() })
+        );
+        assert_eq!(
+            arrange_phrase("i1s2 Th3is"),
+            Err(ArrangeError::MultiplePositions { word: "i1s2".to_owned() })
+        );
+        assert_eq!(
+            arrange_phrase("is2 Thi2s"),
+            Err(ArrangeError::DuplicatePosition { word: "Thi2s".to_owned(), position: 2 })
+        );
+        assert_eq!(
+            arrange_phrase("is3 Thi1s"),
+            Err(ArrangeError::OutOfRange { word: "is3".to_owned(), position: 3 })
+        );
+        assert_eq!(
+            arrange_phrase("is0"),
+            Err(ArrangeError::OutOfRange { word: "is0".to_owned(), position: 0 })
+        );
+        assert!(matches!(
+            arrange_phrase("a99999999999999999999999"),
+            Err(ArrangeError::OutOfRange { .. })
+        ));
+        assert_eq!(
+            scramble_phrase("route 66", &mut StdRng::seed_from_u64(1)),
+            Err(ArrangeError::WordHasDigits { word: "66".to_owned() })
+        );
+    }
+
+    #[test]
+    fn test_scramble_is_reproducible() {
+        let phrase = "one two three four five";
+        let a = scramble_phrase(phrase, &mut StdRng::seed_from_u64(7)).unwrap();
+        let b = scramble_phrase(phrase, &mut StdRng::seed_from_u64(7)).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_round_trip_property() {
+        let alphabet: Vec<char> = "abcdefghijklmnopqrstuvwxyzÀéß-'".chars().collect();
+        for seed in 0..300 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let words: Vec<String> = (0..rng.gen_range(0..30))
+                .map(|_| (0..rng.gen_range(1..8)).map(|_| alphabet[rng.gen_range(0..alphabet.len())]).collect())
+                .collect();
+            let phrase = words.join(" ");
+            let scrambled = scramble_phrase(&phrase, &mut rng).unwrap();
+            assert_eq!(arrange_phrase(&scrambled), Ok(phrase), "seed {}", seed);
+        }
+    }
+}
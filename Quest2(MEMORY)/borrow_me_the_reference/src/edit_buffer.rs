@@ -0,0 +1,274 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    Undo,
+    Redo,
+}
+
+//* one undoable change; the cursor position before the change is kept so undo puts it back
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Edit {
+    Insert { at: usize, text: Vec<char>, cursor: usize },
+    Delete { at: usize, text: Vec<char>, cursor: usize },
+}
+
+//* a single line of text with a cursor between chars (0 is before the first one) and an undo/redo history
+//* cursor moves are not recorded in the history, only changes to the text are
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditBuffer {
+    text: Vec<char>,
+    cursor: usize,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+}
+
+impl EditBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(&self) -> String {
+        self.text.iter().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    //* the char right after the cursor, the one `delete` would remove
+    pub fn peek(&self) -> Option<char> {
+        self.text.get(self.cursor).copied()
+    }
+
+    pub fn set_cursor(&mut self, position: usize) {
+        self.cursor = position.min(self.text.len());
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.insert_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    //* inserts at the cursor and leaves the cursor after the new text, as one undo step
+    pub fn insert_str(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+        let edit = Edit::Insert { at: self.cursor, text: s.chars().collect(), cursor: self.cursor };
+        self.apply_edit(&edit);
+        self.undo.push(edit);
+        self.redo.clear();
+    }
+
+    pub fn backspace(&mut self) -> Option<char> {
+        if self.cursor == 0 {
+            return None;
+        }
+        self.remove(self.cursor - 1)
+    }
+
+    pub fn delete(&mut self) -> Option<char> {
+        if self.cursor == self.text.len() {
+            return None;
+        }
+        self.remove(self.cursor)
+    }
+
+    fn remove(&mut self, at: usize) -> Option<char> {
+        let c = self.text[at];
+        let edit = Edit::Delete { at, text: vec![c], cursor: self.cursor };
+        self.apply_edit(&edit);
+        self.undo.push(edit);
+        self.redo.clear();
+        Some(c)
+    }
+
+    pub fn move_left(&mut self) -> bool {
+        let moved = self.cursor > 0;
+        self.cursor -= usize::from(moved);
+        moved
+    }
+
+    pub fn move_right(&mut self) -> bool {
+        let moved = self.cursor < self.text.len();
+        self.cursor += usize::from(moved);
+        moved
+    }
+
+    pub fn home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn end(&mut self) {
+        self.cursor = self.text.len();
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.undo.pop() else {
+            return false;
+        };
+        match &edit {
+            Edit::Insert { at, text, cursor } => {
+                self.text.drain(*at..at + text.len());
+                self.cursor = *cursor;
+            }
+            Edit::Delete { at, text, cursor } => {
+                self.text.splice(*at..*at, text.iter().copied());
+                self.cursor = *cursor;
+            }
+        }
+        self.redo.push(edit);
+        true
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        self.apply_edit(&edit);
+        self.undo.push(edit);
+        true
+    }
+
+    fn apply_edit(&mut self, edit: &Edit) {
+        match edit {
+            Edit::Insert { at, text, .. } => {
+                self.text.splice(*at..*at, text.iter().copied());
+                self.cursor = at + text.len();
+            }
+            Edit::Delete { at, text, .. } => {
+                self.text.drain(*at..at + text.len());
+                self.cursor = *at;
+            }
+        }
+    }
+
+    pub fn press(&mut self, key: Key) {
+        match key {
+            Key::Char(c) => self.insert(c),
+            Key::Backspace => {
+                self.backspace();
+            }
+            Key::Delete => {
+                self.delete();
+            }
+            Key::Left => {
+                self.move_left();
+            }
+            Key::Right => {
+                self.move_right();
+            }
+            Key::Home => self.home(),
+            Key::End => self.end(),
+            Key::Undo => {
+                self.undo();
+            }
+            Key::Redo => {
+                self.redo();
+            }
+        }
+    }
+
+    pub fn replay(&mut self, script: &str) -> Result<(), ScriptError> {
+        for key in parse_script(script)? {
+            self.press(key);
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for EditBuffer {
+    //* the text is loaded as-is with the cursor at the start and an empty history
+    fn from(s: &str) -> Self {
+        EditBuffer { text: s.chars().collect(), ..Self::default() }
+    }
+}
+
+impl fmt::Display for EditBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.text())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    //* char offset of the `{` that starts the bad key
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "at {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+//* the largest count accepted in `{KEY n}`, a script is expanded to its keys before it is replayed
+pub const MAX_REPEAT: usize = 10_000;
+
+//* plain chars are typed; special keys go in braces with an optional repeat count:
+//* {BS} {DEL} {LEFT} {RIGHT} {HOME} {END} {UNDO} {REDO}, e.g. "helo{LEFT}l{END}!{BS 2}"; `{{` types a literal `{`
+pub fn parse_script(script: &str) -> Result<Vec<Key>, ScriptError> {
+    let chars: Vec<char> = script.chars().collect();
+    let mut keys = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '{' {
+            keys.push(Key::Char(chars[i]));
+            i += 1;
+            continue;
+        }
+        if chars.get(i + 1) == Some(&'{') {
+            keys.push(Key::Char('{'));
+            i += 2;
+            continue;
+        }
+        let err = |message: String| ScriptError { position: i, message };
+        let close = chars[i..]
+            .iter()
+            .position(|&c| c == '}')
+            .ok_or_else(|| err("unclosed `{`".to_owned()))?;
+        let inner: String = chars[i + 1..i + close].iter().collect();
+        let mut parts = inner.split_whitespace();
+        let name = parts.next().unwrap_or_default();
+        let key = match name.to_uppercase().as_str() {
+            "BS" | "BACKSPACE" => Key::Backspace,
+            "DEL" | "DELETE" => Key::Delete,
+            "LEFT" => Key::Left,
+            "RIGHT" => Key::Right,
+            "HOME" => Key::Home,
+            "END" => Key::End,
+            "UNDO" => Key::Undo,
+            "REDO" => Key::Redo,
+            _ => return Err(err(format!("unknown key {:?}", name))),
+        };
+        let count = match (parts.next(), parts.next()) {
+            (None, _) => 1,
+            (Some(n), None) => n.parse::<usize>().map_err(|_| err(format!("invalid repeat count {:?}", n)))?,
+            (Some(_), Some(_)) => return Err(err(format!("too many arguments in {{{}}}", inner))),
+        };
+        if count > MAX_REPEAT {
+            return Err(err(format!("repeat count {} is larger than {}", count, MAX_REPEAT)));
+        }
+        keys.extend(std::iter::repeat_n(key, count));
+        i += close + 1;
+    }
+    Ok(keys)
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnexpectedChar(char),
+    UnexpectedEnd,
+    //* a token in a place where it makes no sense, like the `*` in "2+*3" or the `)` in "2)"
+    UnexpectedToken,
+    UnclosedParen,
+    NumberTooLarge,
+    DivisionByZero,
+    Overflow,
+    //* more than `MAX_DEPTH` nested parentheses or unary signs
+    TooDeep,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvalError {
+    //* char offset into the expression (0-based) of the token that caused the error
+    pub position: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {:?}", c)?,
+            ErrorKind::UnexpectedEnd => write!(f, "unexpected end of expression")?,
+            ErrorKind::UnexpectedToken => write!(f, "unexpected token")?,
+            ErrorKind::UnclosedParen => write!(f, "unclosed parenthesis")?,
+            ErrorKind::NumberTooLarge => write!(f, "number too large")?,
+            ErrorKind::DivisionByZero => write!(f, "division by zero")?,
+            ErrorKind::Overflow => write!(f, "integer overflow")?,
+            ErrorKind::TooDeep => write!(f, "expression nested too deeply")?,
+        }
+        write!(f, " at {}", self.position)
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Number(i64),
+    Op(char),
+    Open,
+    Close,
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, EvalError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let token = match c {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '0'..='9' => {
+                let start = i;
+                let mut value: i64 = 0;
+                while let Some(d) = chars.get(i).and_then(|c| c.to_digit(10)) {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(d as i64))
+                        .ok_or(EvalError { position: start, kind: ErrorKind::NumberTooLarge })?;
+                    i += 1;
+                }
+                tokens.push((start, Token::Number(value)));
+                continue;
+            }
+            '+' | '-' | '*' | '/' | '%' => Token::Op(c),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            _ => return Err(EvalError { position: i, kind: ErrorKind::UnexpectedChar(c) }),
+        };
+        tokens.push((i, token));
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+fn precedence(op: char) -> u8 {
+    match op {
+        '+' | '-' => 1,
+        _ => 2,
+    }
+}
+
+//* the parser recurses once per '(' and per unary sign, this keeps "-----...1" from overflowing the stack
+pub const MAX_DEPTH: usize = 256;
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    //* where the input ends, reported for errors like "2+"
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<(usize, Token)> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Result<(usize, Token), EvalError> {
+        let token = self.peek().ok_or(EvalError { position: self.end, kind: ErrorKind::UnexpectedEnd })?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    //* precedence climbing: parse an operand, then keep folding in operators that bind at least as tight as `min`
+    //* `depth` counts the parentheses and unary signs around this point
+    fn expression(&mut self, min: u8, depth: usize) -> Result<i64, EvalError> {
+        let mut lhs = self.unary(depth)?;
+        while let Some((at, Token::Op(op))) = self.peek() {
+            if precedence(op) < min {
+                break;
+            }
+            self.pos += 1;
+            //* + 1 makes the operators left associative: 8-3-2 is (8-3)-2
+            let rhs = self.expression(precedence(op) + 1, depth)?;
+            lhs = apply(op, lhs, rhs).map_err(|kind| EvalError { position: at, kind })?;
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self, depth: usize) -> Result<i64, EvalError> {
+        let token = self.next()?;
+        if depth >= MAX_DEPTH && matches!(token.1, Token::Op('-' | '+') | Token::Open) {
+            return Err(EvalError { position: token.0, kind: ErrorKind::TooDeep });
+        }
+        match token {
+            (at, Token::Op('-')) => self
+                .unary(depth + 1)?
+                .checked_neg()
+                .ok_or(EvalError { position: at, kind: ErrorKind::Overflow }),
+            (_, Token::Op('+')) => self.unary(depth + 1),
+            (_, Token::Number(n)) => Ok(n),
+            (at, Token::Open) => {
+                let value = self.expression(0, depth + 1)?;
+                match self.peek() {
+                    Some((_, Token::Close)) => {
+                        self.pos += 1;
+                        Ok(value)
+                    }
+                    Some((position, _)) => Err(EvalError { position, kind: ErrorKind::UnexpectedToken }),
+                    None => Err(EvalError { position: at, kind: ErrorKind::UnclosedParen }),
+                }
+            }
+            (position, _) => Err(EvalError { position, kind: ErrorKind::UnexpectedToken }),
+        }
+    }
+}
+
+fn apply(op: char, a: i64, b: i64) -> Result<i64, ErrorKind> {
+    if matches!(op, '/' | '%') && b == 0 {
+        return Err(ErrorKind::DivisionByZero);
+    }
+    let result = match op {
+        '+' => a.checked_add(b),
+        '-' => a.checked_sub(b),
+        '*' => a.checked_mul(b),
+        '/' => a.checked_div(b),
+        _ => a.checked_rem(b),
+    };
+    result.ok_or(ErrorKind::Overflow)
+}
+
+//* integer arithmetic with + - * / % (truncating division), unary minus and parentheses, on i64
+pub fn evaluate(input: &str) -> Result<i64, EvalError> {
+    let mut parser = Parser { tokens: tokenize(input)?, pos: 0, end: input.chars().count() };
+    let value = parser.expression(0, 0)?;
+    match parser.peek() {
+        None => Ok(value),
+        Some((position, _)) => Err(EvalError { position, kind: ErrorKind::UnexpectedToken }),
+    }
+}
@@ -1,53 +1,49 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file7
 
-pub fn delete_and_backspace(s: &mut String) {
-    let mut result = String::new();
-    let mut skip_next = false;
-    let mut n = 0;
-
-    for (i , c) in s.char_indices() {
-        if n > 0 && c != '+'{
-            n -= 1;
-            continue;
-        }
-        if skip_next && c == '+' {
-            n += 1;
-            continue;
-        }
-        if skip_next && c != '+'{
-            skip_next = false;
-            continue;
-        }
+mod edit_buffer;
+mod expr;
+pub use edit_buffer::{parse_script, EditBuffer, Key, ScriptError, MAX_REPEAT};
+pub use expr::{evaluate, ErrorKind, EvalError, MAX_DEPTH};
 
+//* `-` is a backspace and every `+` deletes one of the chars that come after it in the input;
+//* the input is loaded into an EditBuffer and the markers are carried out on it one by one
+pub fn delete_and_backspace(s: &mut String) {
+    let mut buffer = EditBuffer::from(s.as_str());
+    //* how many chars after the cursor still have to be deleted because of earlier `+`
+    let mut pending = 0;
+    while let Some(c) = buffer.peek() {
         match c {
-            '-' => {
-                result.pop();
-            }
+            //* a `+` met while deleting doesn't count as deleted, it asks for one more
             '+' => {
-                skip_next = true;
+                buffer.delete();
+                pending += 1;
+            }
+            _ if pending > 0 => {
+                buffer.delete();
+                pending -= 1;
+            }
+            '-' => {
+                buffer.delete();
+                buffer.backspace();
             }
             _ => {
-                result.push(c);
+                buffer.move_right();
             }
         }
     }
-
-    *s = result;
+    *s = buffer.text();
 }
 
+//* every entry that fails to evaluate is replaced by this marker followed by the error
+pub const ERROR_MARKER: &str = "#ERR";
+
+//* replaces each expression with its value, or with "#ERR <reason> at <position>"
 pub fn do_operations(v: &mut [String]) {
     for x in v.iter_mut() {
-        if x.contains('+') {
-            let parts: Vec<&str> = x.split('+').collect();
-            let a: i32 = parts[0].parse().unwrap();
-            let b: i32 = parts[1].parse().unwrap();
-            *x = (a + b).to_string();
-        } else if x.contains('-') {
-            let parts: Vec<&str> = x.split('-').collect();
-            let a: i32 = parts[0].parse().unwrap();
-            let b: i32 = parts[1].parse().unwrap();
-            *x = (a - b).to_string();
-        }
+        *x = match evaluate(x) {
+            Ok(value) => value.to_string(),
+            Err(e) => format!("{} {}", ERROR_MARKER, e),
+        };
     }
 }
 
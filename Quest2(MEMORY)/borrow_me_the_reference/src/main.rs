@@ -17,4 +17,181 @@ fn main() {
 
 // $ cargo run
 // ("borrow", ["4", "5", "7", "10"])
-// $
\ No newline at end of file
+// $
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //* the previous state machine, kept to check the EditBuffer version against it
+    fn legacy_delete_and_backspace(s: &str) -> String {
+        let mut result = String::new();
+        let mut skip_next = false;
+        let mut n = 0;
+        for c in s.chars() {
+            if n > 0 && c != '+' {
+                n -= 1;
+                continue;
+            }
+            if skip_next && c == '+' {
+                n += 1;
+                continue;
+            }
+            if skip_next && c != '+' {
+                skip_next = false;
+                continue;
+            }
+            match c {
+                '-' => {
+                    result.pop();
+                }
+                '+' => skip_next = true,
+                _ => result.push(c),
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_delete_and_backspace() {
+        let mut a = "bpp--o+er+++sskroi-++lcw".to_owned();
+        delete_and_backspace(&mut a);
+        assert_eq!(a, "borrow");
+
+        let mut state = 12345u64;
+        for _ in 0..2000 {
+            let input: String = (0..20)
+                .map(|_| {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    ['a', 'b', 'é', '-', '+'][(state >> 33) as usize % 5]
+                })
+                .collect();
+            let mut s = input.clone();
+            delete_and_backspace(&mut s);
+            assert_eq!(s, legacy_delete_and_backspace(&input), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_editing_and_cursor() {
+        let mut buffer = EditBuffer::new();
+        buffer.insert_str("helo");
+        buffer.move_left();
+        buffer.insert('l');
+        assert_eq!((buffer.text(), buffer.cursor()), ("hello".to_owned(), 4));
+        buffer.home();
+        assert_eq!(buffer.delete(), Some('h'));
+        assert_eq!(buffer.backspace(), None);
+        buffer.end();
+        assert!(!buffer.move_right());
+        assert_eq!(buffer.backspace(), Some('o'));
+        assert_eq!(buffer.text(), "ell");
+        buffer.set_cursor(100);
+        assert_eq!(buffer.cursor(), 3);
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut buffer = EditBuffer::new();
+        buffer.insert_str("abc");
+        buffer.backspace();
+        buffer.home();
+        buffer.insert('x');
+        assert_eq!(buffer.text(), "xab");
+        assert!(buffer.undo());
+        assert_eq!((buffer.text(), buffer.cursor()), ("ab".to_owned(), 0));
+        assert!(buffer.undo());
+        assert_eq!((buffer.text(), buffer.cursor()), ("abc".to_owned(), 3));
+        assert!(buffer.redo());
+        assert_eq!(buffer.text(), "ab");
+        buffer.insert('!');
+        assert!(!buffer.redo());
+        assert!(buffer.undo() && buffer.undo() && buffer.undo());
+        assert_eq!(buffer.text(), "");
+        assert!(!buffer.undo());
+    }
+
+    #[test]
+    fn test_scripts() {
+        let mut buffer = EditBuffer::new();
+        buffer.replay("helo{LEFT}l{END} world{BS 6}!{HOME}{DEL}H").unwrap();
+        assert_eq!(buffer.text(), "Hello!");
+        buffer.replay("{UNDO 2}{REDO}").unwrap();
+        assert_eq!(buffer.text(), "ello!");
+        buffer.replay("{{}").unwrap();
+        assert_eq!(buffer.text(), "{}ello!");
+
+        assert_eq!(
+            parse_script("ab{BS"),
+            Err(ScriptError { position: 2, message: "unclosed `{`".to_owned() })
+        );
+        assert_eq!(parse_script("{JUMP}").unwrap_err().message, "unknown key \"JUMP\"");
+        assert_eq!(parse_script("x{LEFT two}").unwrap_err().position, 1);
+        assert_eq!(parse_script("{left 2}"), Ok(vec![Key::Left, Key::Left]));
+        assert_eq!(parse_script(&format!("{{BS {}}}", MAX_REPEAT)).map(|keys| keys.len()), Ok(MAX_REPEAT));
+        assert_eq!(
+            parse_script(&format!("ab{{BS {}}}", MAX_REPEAT + 1)),
+            Err(ScriptError { position: 2, message: format!("repeat count {} is larger than {}", MAX_REPEAT + 1, MAX_REPEAT) })
+        );
+        assert_eq!(parse_script("{BS 18446744073709551615}").unwrap_err().position, 0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(evaluate("2+2"), Ok(4));
+        assert_eq!(evaluate("-3+4"), Ok(1));
+        assert_eq!(evaluate(" 10 - 3 - 2 "), Ok(5));
+        assert_eq!(evaluate("2 + 3 * 4"), Ok(14));
+        assert_eq!(evaluate("(2 + 3) * 4"), Ok(20));
+        assert_eq!(evaluate("-(2 + 3) * -4 % 7"), Ok(6));
+        assert_eq!(evaluate("7 / -2"), Ok(-3));
+        assert_eq!(evaluate("2--3"), Ok(5));
+        assert_eq!(evaluate("+5"), Ok(5));
+        assert_eq!(evaluate("-9223372036854775807 - 1"), Ok(i64::MIN));
+    }
+
+    #[test]
+    fn test_evaluate_errors() {
+        let err = |position, kind| Err(EvalError { position, kind });
+        assert_eq!(evaluate("1 / (2 - 2)"), err(2, ErrorKind::DivisionByZero));
+        assert_eq!(evaluate("5 % 0"), err(2, ErrorKind::DivisionByZero));
+        assert_eq!(evaluate("9223372036854775807 + 1"), err(20, ErrorKind::Overflow));
+        assert_eq!(evaluate("99999999999999999999"), err(0, ErrorKind::NumberTooLarge));
+        assert_eq!(evaluate("(-9223372036854775807 - 1) / -1"), err(27, ErrorKind::Overflow));
+        assert_eq!(evaluate("2 +"), err(3, ErrorKind::UnexpectedEnd));
+        assert_eq!(evaluate("2 + * 3"), err(4, ErrorKind::UnexpectedToken));
+        assert_eq!(evaluate("(1 + 2"), err(0, ErrorKind::UnclosedParen));
+        assert_eq!(evaluate("1 + 2)"), err(5, ErrorKind::UnexpectedToken));
+        assert_eq!(evaluate("(1 2)"), err(3, ErrorKind::UnexpectedToken));
+        assert_eq!(evaluate("2 ^ 3"), err(2, ErrorKind::UnexpectedChar('^')));
+        assert_eq!(evaluate(""), err(0, ErrorKind::UnexpectedEnd));
+    }
+
+    #[test]
+    fn test_evaluate_depth() {
+        let err = |position, kind| Err(EvalError { position, kind });
+        let signs = |n| "-".repeat(n) + "1";
+        assert_eq!(evaluate(&signs(MAX_DEPTH)), Ok(1));
+        assert_eq!(evaluate(&signs(MAX_DEPTH + 1)), err(MAX_DEPTH, ErrorKind::TooDeep));
+        assert_eq!(evaluate(&signs(1_000_000)), err(MAX_DEPTH, ErrorKind::TooDeep));
+
+        let parens = |n| "(".repeat(n) + "7" + &")".repeat(n);
+        assert_eq!(evaluate(&parens(MAX_DEPTH)), Ok(7));
+        assert_eq!(evaluate(&parens(MAX_DEPTH + 1)), err(MAX_DEPTH, ErrorKind::TooDeep));
+        assert_eq!(evaluate(&"(".repeat(1_000_000)), err(MAX_DEPTH, ErrorKind::TooDeep));
+        //* signs and parentheses add up
+        assert_eq!(evaluate(&("-(".repeat(MAX_DEPTH / 2) + "1" + &")".repeat(MAX_DEPTH / 2))), Ok(1));
+        assert_eq!(evaluate(&("-(".repeat(MAX_DEPTH / 2) + "-1" + &")".repeat(MAX_DEPTH / 2))), err(MAX_DEPTH, ErrorKind::TooDeep));
+        //* a long flat expression is not nested
+        assert_eq!(evaluate(&vec!["1"; 10_000].join("+")), Ok(10_000));
+    }
+
+    #[test]
+    fn test_do_operations() {
+        let mut v = ["2+2", "3+2", "10-3", "5+5", "-3 + 4", "2*(3+4)", "1/0", "abc"].map(String::from);
+        do_operations(&mut v);
+        assert_eq!(
+            v,
+            ["4", "5", "7", "10", "1", "14", "#ERR division by zero at 1", "#ERR unexpected character 'a' at 0"]
+        );
+    }
+}
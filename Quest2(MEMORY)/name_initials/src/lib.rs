@@ -1,27 +1,16 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
 
-pub fn initials(names: Vec<&str>) -> Vec<String> {
-     let mut v: Vec<String> = Vec::with_capacity(names.len());
+mod person_name;
+pub use person_name::{InitialsStyle, NameError, NameOrder, PersonName};
 
-    for name in &names {
-        let mut temp = String::new();
-        for word in name.split_whitespace() {
-            match word.chars().next() {
-                Some(c) => {
-                    temp.push(c);
-                    temp.push('.');
-                    temp.push(' ');
-                }
-                None => (),
-            }
-            
-        }
-        if temp.ends_with(' ') {
-            temp.pop();
-        }
-        v.push(format!("{}",temp));
-    }
-    v
+//* "Harry Potter" -> "H. P.", "Jean-Luc Picard" -> "J.-L. P.", "Ludwig van Beethoven" -> "L. B.";
+//* a name that is only whitespace gives an empty string
+pub fn initials(names: Vec<&str>) -> Vec<String> {
+    let style = InitialsStyle::default();
+    names
+        .iter()
+        .map(|name| PersonName::parse(name).map(|n| n.initials(&style)).unwrap_or_default())
+        .collect()
 }
 
 /*  
@@ -4,3 +4,71 @@ fn main() {
     let names = vec!["Harry Potter", "Someone Else", "J. L.", "Barack Obama"];
     println!("{:?}", initials(names));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initials() {
+        let names = vec!["Harry Potter", "Someone Else", "J. L.", "Barack Obama", "Jean-Luc Picard", "Ludwig van Beethoven", "Martin Luther King, Jr.", "  "];
+        assert_eq!(initials(names), vec!["H. P.", "S. E.", "J. L.", "B. O.", "J.-L. P.", "L. B.", "M. L. K.", ""]);
+    }
+
+    #[test]
+    fn test_parse_parts() {
+        let name = PersonName::parse("Martin Luther King, Jr.").unwrap();
+        assert_eq!((name.given(), name.middle(), name.family(), name.suffix()), ("Martin", &["Luther".to_string()][..], Some("King"), Some("Jr.")));
+
+        let name = PersonName::parse("Juan Carlos de la Cruz III").unwrap();
+        assert_eq!(name.particles(), ["de", "la"]);
+        assert_eq!(name.middle(), ["Carlos"]);
+        assert_eq!(name.family(), Some("Cruz"));
+        assert_eq!(name.suffix(), Some("III"));
+        assert_eq!(name.to_string(), "Juan Carlos de la Cruz, III");
+
+        let name = PersonName::parse("Madonna").unwrap();
+        assert_eq!((name.given(), name.family()), ("Madonna", None));
+        //* a given name that looks like a particle stays the given name
+        assert_eq!(PersonName::parse("Van Morrison").unwrap().given(), "Van");
+        assert_eq!(PersonName::parse(" , "), Err(NameError::Empty));
+    }
+
+    #[test]
+    fn test_family_first() {
+        let name = PersonName::parse_with_order("Kim Jong Un", NameOrder::FamilyFirst).unwrap();
+        assert_eq!((name.family(), name.given(), name.middle()), (Some("Kim"), "Jong", &["Un".to_string()][..]));
+        assert_eq!(name.initials(&InitialsStyle::default()), "K. J. U.");
+        assert_eq!(name.to_string(), "Kim Jong Un");
+    }
+
+    #[test]
+    fn test_styles() {
+        let name = PersonName::parse("Ludwig van Beethoven").unwrap();
+        assert_eq!(name.initials(&InitialsStyle::compact()), "LB");
+        let style = InitialsStyle { particles: true, ..InitialsStyle::default() };
+        assert_eq!(name.initials(&style), "L. v. B.");
+
+        let name = PersonName::parse("martin luther king jr").unwrap();
+        let style = InitialsStyle { middle: false, suffix: true, uppercase: true, ..InitialsStyle::default() };
+        assert_eq!(name.initials(&style), "M. K. jr");
+        assert_eq!(PersonName::parse("Jean-Luc Picard").unwrap().initials(&InitialsStyle::compact()), "J-LP");
+    }
+
+    #[test]
+    fn test_scripts() {
+        let style = InitialsStyle::default();
+        let initials_of = |s| PersonName::parse(s).unwrap().initials(&style);
+        assert_eq!(initials_of("Фёдор Достоевский"), "Ф. Д.");
+        assert_eq!(initials_of("Ἀριστοτέλης Σταγειρίτης"), "Ἀ. Σ.");
+        //* "E" + combining acute stays one initial
+        assert_eq!(initials_of("E\u{301}mile Zola"), "E\u{301}. Z.");
+        assert_eq!(initials_of("की रवि"), "की. र.");
+        assert_eq!(initials_of("محمد علي"), "م. ع.");
+        assert_eq!(initials_of("\u{2018}Arthur O'Neill"), "A. O.");
+        //* whole grapheme clusters, whatever the script: a conjunct, a Gurmukhi vowel sign, decomposed Hangul
+        assert_eq!(initials_of("क्षमा राव"), "क्ष. रा.");
+        assert_eq!(initials_of("ਗੁਰੂ ਨਾਨਕ"), "ਗੁ. ਨਾ.");
+        assert_eq!(initials_of("\u{1100}\u{1161}\u{11A8} \u{1102}\u{1161}"), "\u{1100}\u{1161}\u{11A8}. \u{1102}\u{1161}.");
+    }
+}
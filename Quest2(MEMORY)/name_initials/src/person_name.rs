@@ -0,0 +1,241 @@
+use std::fmt;
+
+use reverse_string::graphemes;
+
+//* lowercase forms of the words that sit between the given and the family name ("van", "de la", "bin", ...)
+const PARTICLES: [&str; 24] = [
+    "van", "von", "der", "den", "de", "del", "della", "di", "da", "dos", "das", "do", "du", "la", "le",
+    "ten", "ter", "zu", "af", "bin", "ibn", "al", "el", "st.",
+];
+
+//* compared without the trailing '.' and case-insensitively, so "Jr", "jr." and "JR." all match
+const SUFFIXES: [&str; 10] = ["jr", "sr", "ii", "iii", "iv", "phd", "md", "esq", "dds", "obe"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameOrder {
+    //* "Harry James Potter"
+    GivenFirst,
+    //* "Mao Zedong", "Kim Jong Un": the first word is the family name
+    FamilyFirst,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameError {
+    //* nothing but whitespace and commas
+    Empty,
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NameError::Empty => write!(f, "the name is empty"),
+        }
+    }
+}
+
+impl std::error::Error for NameError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonName {
+    given: String,
+    middle: Vec<String>,
+    particles: Vec<String>,
+    //* None for mononyms like "Madonna"
+    family: Option<String>,
+    suffix: Option<String>,
+    order: NameOrder,
+}
+
+//* what ends up in the initials and how they are written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitialsStyle {
+    //* "J." instead of "J"
+    pub periods: bool,
+    //* put between two initials, hyphenated names always keep their '-'
+    pub separator: String,
+    pub middle: bool,
+    //* "L. v. B." instead of "L. B." for "Ludwig van Beethoven"
+    pub particles: bool,
+    //* appended as written: "M. L. K. Jr."
+    pub suffix: bool,
+    pub uppercase: bool,
+}
+
+impl Default for InitialsStyle {
+    fn default() -> Self {
+        InitialsStyle { periods: true, separator: " ".to_string(), middle: true, particles: false, suffix: false, uppercase: false }
+    }
+}
+
+impl InitialsStyle {
+    //* "HJP" for "Harry James Potter", the monogram style
+    pub fn compact() -> Self {
+        InitialsStyle { periods: false, separator: String::new(), uppercase: true, ..Self::default() }
+    }
+}
+
+impl PersonName {
+    pub fn parse(s: &str) -> Result<Self, NameError> {
+        Self::parse_with_order(s, NameOrder::GivenFirst)
+    }
+
+    pub fn parse_with_order(s: &str, order: NameOrder) -> Result<Self, NameError> {
+        //* "King, Jr." : the comma only separates the suffix, it is not part of any word
+        let mut words: Vec<&str> = s
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|w| !w.is_empty())
+            .collect();
+        if words.is_empty() {
+            return Err(NameError::Empty);
+        }
+        let suffix = match words.last() {
+            Some(last) if words.len() > 1 && is_suffix(last) => words.pop().map(str::to_string),
+            _ => None,
+        };
+        let mut name = PersonName {
+            given: String::new(),
+            middle: Vec::new(),
+            particles: Vec::new(),
+            family: None,
+            suffix,
+            order,
+        };
+        match order {
+            NameOrder::GivenFirst => {
+                name.given = words.remove(0).to_string();
+                if let Some(family) = words.pop() {
+                    name.family = Some(family.to_string());
+                }
+                //* particles are the run of particle words right before the family name
+                let start = words.iter().rposition(|w| !is_particle(w)).map_or(0, |i| i + 1);
+                name.particles = words.drain(start..).map(str::to_string).collect();
+                name.middle = words.into_iter().map(str::to_string).collect();
+            }
+            NameOrder::FamilyFirst => {
+                let start = words.iter().position(|w| !is_particle(w)).unwrap_or(0);
+                name.particles = words.drain(..start).map(str::to_string).collect();
+                let family = words.remove(0).to_string();
+                if words.is_empty() {
+                    name.given = family;
+                } else {
+                    name.family = Some(family);
+                    name.given = words.remove(0).to_string();
+                    name.middle = words.into_iter().map(str::to_string).collect();
+                }
+            }
+        }
+        Ok(name)
+    }
+
+    pub fn given(&self) -> &str {
+        &self.given
+    }
+
+    pub fn middle(&self) -> &[String] {
+        &self.middle
+    }
+
+    pub fn particles(&self) -> &[String] {
+        &self.particles
+    }
+
+    pub fn family(&self) -> Option<&str> {
+        self.family.as_deref()
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+
+    pub fn order(&self) -> NameOrder {
+        self.order
+    }
+
+    //* every word of the name in the order it is written, tagged with the part it belongs to
+    fn words(&self) -> Vec<(Part, &str)> {
+        let mut words = Vec::new();
+        let particles = self.particles.iter().map(|p| (Part::Particle, p.as_str()));
+        let family = self.family.iter().map(|f| (Part::Family, f.as_str()));
+        let given = std::iter::once((Part::Given, self.given.as_str()))
+            .chain(self.middle.iter().map(|m| (Part::Middle, m.as_str())));
+        match self.order {
+            NameOrder::GivenFirst => words.extend(given.chain(particles).chain(family)),
+            NameOrder::FamilyFirst => words.extend(particles.chain(family).chain(given)),
+        }
+        words
+    }
+
+    pub fn initials(&self, style: &InitialsStyle) -> String {
+        let mut parts: Vec<String> = self
+            .words()
+            .into_iter()
+            .filter(|(part, _)| match part {
+                Part::Middle => style.middle,
+                Part::Particle => style.particles,
+                _ => true,
+            })
+            .map(|(_, word)| word_initials(word, style))
+            .filter(|s| !s.is_empty())
+            .collect();
+        if style.suffix {
+            if let Some(suffix) = &self.suffix {
+                parts.push(suffix.clone());
+            }
+        }
+        parts.join(&style.separator)
+    }
+}
+
+impl fmt::Display for PersonName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let words: Vec<&str> = self.words().into_iter().map(|(_, w)| w).collect();
+        write!(f, "{}", words.join(" "))?;
+        if let Some(suffix) = &self.suffix {
+            write!(f, ", {}", suffix)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Part {
+    Given,
+    Middle,
+    Particle,
+    Family,
+}
+
+fn is_particle(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    PARTICLES.contains(&lower.as_str()) || lower.starts_with("d'")
+}
+
+fn is_suffix(word: &str) -> bool {
+    let lower = word.trim_end_matches('.').to_lowercase();
+    SUFFIXES.contains(&lower.as_str())
+}
+
+//* "Jean-Luc" gives "J.-L.", each piece of a hyphenated word gets its own initial
+fn word_initials(word: &str, style: &InitialsStyle) -> String {
+    let pieces: Vec<String> = word
+        .split('-')
+        .filter_map(|piece| {
+            let mut initial = first_letter(piece)?;
+            if style.uppercase {
+                initial = initial.to_uppercase();
+            }
+            if style.periods {
+                initial.push('.');
+            }
+            Some(initial)
+        })
+        .collect();
+    pieces.join("-")
+}
+
+//* the first grapheme cluster, so "É" written as E + U+0301, a Devanagari consonant with its
+//* vowel sign or a conjunct like "क्ष" stays whole; leading quotes and such are skipped
+fn first_letter(piece: &str) -> Option<String> {
+    let start = piece.find(char::is_alphanumeric)?;
+    graphemes(&piece[start..]).next().map(str::to_string)
+}
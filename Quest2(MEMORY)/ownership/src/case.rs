@@ -0,0 +1,173 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    //* httpServerError
+    Camel,
+    //* HttpServerError
+    Pascal,
+    //* http_server_error
+    Snake,
+    //* http-server-error
+    Kebab,
+    //* HTTP_SERVER_ERROR
+    ScreamingSnake,
+    //* HTTP-SERVER-ERROR
+    ScreamingKebab,
+    //* Http-Server-Error
+    Train,
+    //* Http Server Error
+    Title,
+}
+
+impl Case {
+    pub const ALL: [Case; 8] = [
+        Case::Camel,
+        Case::Pascal,
+        Case::Snake,
+        Case::Kebab,
+        Case::ScreamingSnake,
+        Case::ScreamingKebab,
+        Case::Train,
+        Case::Title,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Case::Camel => "camelCase",
+            Case::Pascal => "PascalCase",
+            Case::Snake => "snake_case",
+            Case::Kebab => "kebab-case",
+            Case::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            Case::ScreamingKebab => "SCREAMING-KEBAB-CASE",
+            Case::Train => "Train-Case",
+            Case::Title => "Title Case",
+        }
+    }
+
+    fn separator(self) -> &'static str {
+        match self {
+            Case::Camel | Case::Pascal => "",
+            Case::Snake | Case::ScreamingSnake => "_",
+            Case::Kebab | Case::ScreamingKebab | Case::Train => "-",
+            Case::Title => " ",
+        }
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCase(pub String);
+
+impl fmt::Display for UnknownCase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown case style {:?}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownCase {}
+
+//* accepts the names from `Case::name` and short ones like "camel", "screaming-snake", "upper_snake"
+impl FromStr for Case {
+    type Err = UnknownCase;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key: String = s.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+        let key = key.strip_suffix("case").unwrap_or(&key);
+        match key {
+            "camel" | "lowercamel" => Ok(Case::Camel),
+            "pascal" | "uppercamel" => Ok(Case::Pascal),
+            "snake" => Ok(Case::Snake),
+            "kebab" | "dash" => Ok(Case::Kebab),
+            "screamingsnake" | "uppersnake" | "constant" => Ok(Case::ScreamingSnake),
+            "screamingkebab" | "upperkebab" | "cobol" => Ok(Case::ScreamingKebab),
+            "train" => Ok(Case::Train),
+            "title" => Ok(Case::Title),
+            _ => Err(UnknownCase(s.to_string())),
+        }
+    }
+}
+
+//* yields the words of an identifier in any of the styles above, as slices of the input:
+//* "HTTPServerError" -> "HTTP", "Server", "Error" ; "parse_utf8Bytes" -> "parse", "utf8", "Bytes"
+#[derive(Debug, Clone)]
+pub struct Words<'a> {
+    rest: &'a str,
+}
+
+pub fn words(s: &str) -> Words<'_> {
+    Words { rest: s }
+}
+
+//* '_', '-', spaces and any other punctuation only separate words, they never belong to one
+fn is_separator(c: char) -> bool {
+    !c.is_alphanumeric()
+}
+
+//* a new word starts at `c` when it is the hump of a camel ("helloWorld"), the first letter after
+//* digits ("v2Api") or the last capital of an acronym that is followed by a lowercase ("HTTPServer")
+fn starts_word(prev: char, c: char, next: Option<char>) -> bool {
+    if !c.is_uppercase() {
+        return false;
+    }
+    if prev.is_lowercase() || prev.is_numeric() {
+        return true;
+    }
+    prev.is_uppercase() && next.is_some_and(char::is_lowercase)
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let start = self.rest.find(|c: char| !is_separator(c))?;
+        let s = &self.rest[start..];
+        let mut chars = s.char_indices().peekable();
+        let (_, mut prev) = chars.next()?;
+        let mut end = s.len();
+        while let Some((i, c)) = chars.next() {
+            let next = chars.peek().map(|&(_, n)| n);
+            if is_separator(c) || starts_word(prev, c, next) {
+                end = i;
+                break;
+            }
+            prev = c;
+        }
+        self.rest = &s[end..];
+        Some(&s[..end])
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.as_str().to_lowercase().chars()).collect(),
+        None => String::new(),
+    }
+}
+
+//* acronyms are not kept as they are: "HTTPServer" in snake_case is "http_server" and in
+//* camelCase "httpServer", so converting the result back gives the same words
+pub fn to_case(s: &str, case: Case) -> String {
+    let words: Vec<String> = words(s)
+        .enumerate()
+        .map(|(i, word)| match case {
+            Case::Snake | Case::Kebab => word.to_lowercase(),
+            Case::ScreamingSnake | Case::ScreamingKebab => word.to_uppercase(),
+            Case::Camel if i == 0 => word.to_lowercase(),
+            Case::Camel | Case::Pascal | Case::Train | Case::Title => capitalize(word),
+        })
+        .collect();
+    words.join(case.separator())
+}
+
+//* true when converting `s` to `case` gives back `s`, so "" and "x" are in every lowercase style
+pub fn is_case(s: &str, case: Case) -> bool {
+    to_case(s, case) == s
+}
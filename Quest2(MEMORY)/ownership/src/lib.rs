@@ -1,19 +1,20 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file
 
-pub fn first_subword(mut s: String) -> String {
-    for (i, c) in s.char_indices().skip(1) {
-        if c.is_uppercase() || c == '_' {
-            return s[..i].to_string();
-        }
+mod case;
+pub use case::{is_case, to_case, words, Case, UnknownCase, Words};
 
+//* the first word of an identifier in any case style, the whole string if it has no words at all
+pub fn first_subword(s: String) -> String {
+    match words(&s).next() {
+        Some(word) => word.to_string(),
+        None => s,
     }
-    return s
 }
 
 /*
     * Q & A:
-    * Q1: what does s.char_indices() and skip(1) do?
-    - s.char_indices() returns an iterator that yields pairs of (index, character) for each character in the string. The skip(1) method is used to skip the first character because we want to start checking from the second character onwards. This is because the first character can be uppercase or an underscore, and we want to ignore that case when determining the first subword.
-    * Q2: why do we return s[..i].to_string() instead of just s[..i]?
-    - s[..i] returns a string slice (&str) that represents the substring from the beginning of the string up to index i. However, the function signature specifies that we need to return a String, which is an owned type. Therefore, we call to_string() on the string slice to convert it into a String before returning it.
+    * Q1: what does words(&s).next() do?
+    - words(&s) returns an iterator over the words of the identifier, each one a &str slice of s. next() asks it for the first word only, so the rest of the string is never split. Separators like '_' and '-' are skipped before a word starts, which is why "__init__" gives "init" and "--dry-run" gives "dry" instead of an empty string.
+    * Q2: why do we return word.to_string() instead of just word?
+    - word is a string slice (&str) that borrows from s. However, the function signature specifies that we need to return a String, which is an owned type, and s is dropped at the end of the function. Therefore, we call to_string() on the slice to copy it into a new String before returning it. When there is no word at all ("" or "___") we can hand back s itself, no copy needed.
 */
@@ -5,7 +5,7 @@ fn main() {
     let s2 = "snake_case";
     let s3 = "CamelCase";
     let s4 = "just";
-    let s5 = s1.to_owned();
+    let _s5 = s1.to_owned();
 
     println!("first_subword({}) = {}", s1, first_subword(s1.to_owned()));
     println!("first_subword({}) = {}", s2, first_subword(s2.to_owned()));
@@ -13,8 +13,8 @@ fn main() {
     println!("first_subword({}) = {}", s4, first_subword(s4.to_owned()));
     
     
-    // println!("first_subword({}) = {}", s1, first_subword(s5));
-    // println!("{}", s5); // value borrowed here after move!
+    // println!("first_subword({}) = {}", s1, first_subword(_s5));
+    // println!("{}", _s5); // value borrowed here after move!
 }
 
 // $ cargo run
@@ -22,4 +22,83 @@ fn main() {
 // first_subword(snake_case) = snake
 // first_subword(CamelCase) = Camel
 // first_subword(just) = just
-// $
\ No newline at end of file
+// $
+#[cfg(test)]
+mod tests {
+    use ownership::*;
+
+    #[test]
+    fn test_first_subword() {
+        assert_eq!(first_subword("helloWorld".to_owned()), "hello");
+        assert_eq!(first_subword("snake_case".to_owned()), "snake");
+        assert_eq!(first_subword("CamelCase".to_owned()), "Camel");
+        assert_eq!(first_subword("just".to_owned()), "just");
+        assert_eq!(first_subword("HTTPServer".to_owned()), "HTTP");
+        //* leading separators are skipped, they don't make an empty first word
+        assert_eq!(first_subword("__init__".to_owned()), "init");
+        assert_eq!(first_subword("_privateField".to_owned()), "private");
+        assert_eq!(first_subword("--dry-run".to_owned()), "dry");
+        assert_eq!(first_subword("-_Mixed_case".to_owned()), "Mixed");
+        assert_eq!(first_subword("___".to_owned()), "___");
+        assert_eq!(first_subword("".to_owned()), "");
+    }
+
+    #[test]
+    fn test_words() {
+        let split = |s| words(s).collect::<Vec<_>>();
+        assert_eq!(split("HTTPServerError"), ["HTTP", "Server", "Error"]);
+        assert_eq!(split("getHTTPResponseCode"), ["get", "HTTP", "Response", "Code"]);
+        assert_eq!(split("XMLHttpRequest"), ["XML", "Http", "Request"]);
+        assert_eq!(split("snake_case_name"), ["snake", "case", "name"]);
+        assert_eq!(split("kebab-case--name"), ["kebab", "case", "name"]);
+        assert_eq!(split("SCREAMING_SNAKE"), ["SCREAMING", "SNAKE"]);
+        assert_eq!(split("  Title Case "), ["Title", "Case"]);
+        assert_eq!(split("parse_utf8Bytes"), ["parse", "utf8", "Bytes"]);
+        assert_eq!(split("v2Api"), ["v2", "Api"]);
+        assert_eq!(split("HTTP2Server"), ["HTTP2", "Server"]);
+        assert_eq!(split("sha256"), ["sha256"]);
+        assert_eq!(split("A"), ["A"]);
+        assert_eq!(split("élèveÉcole"), ["élève", "École"]);
+        assert_eq!(split("__"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_to_case() {
+        let s = "HTTPServerError";
+        assert_eq!(to_case(s, Case::Camel), "httpServerError");
+        assert_eq!(to_case(s, Case::Pascal), "HttpServerError");
+        assert_eq!(to_case(s, Case::Snake), "http_server_error");
+        assert_eq!(to_case(s, Case::Kebab), "http-server-error");
+        assert_eq!(to_case(s, Case::ScreamingSnake), "HTTP_SERVER_ERROR");
+        assert_eq!(to_case(s, Case::ScreamingKebab), "HTTP-SERVER-ERROR");
+        assert_eq!(to_case(s, Case::Train), "Http-Server-Error");
+        assert_eq!(to_case(s, Case::Title), "Http Server Error");
+        assert_eq!(to_case("user_id2_value", Case::Camel), "userId2Value");
+        assert_eq!(to_case("", Case::Pascal), "");
+    }
+
+    #[test]
+    fn test_round_trips() {
+        for input in ["HTTPServerError", "parse_utf8_bytes", "myVar2Name", "Already-Train-Case"] {
+            for from in Case::ALL {
+                let converted = to_case(input, from);
+                assert!(is_case(&converted, from), "{} as {}", input, from);
+                for to in Case::ALL {
+                    assert_eq!(to_case(&converted, to), to_case(input, to), "{} -> {} -> {}", input, from, to);
+                }
+            }
+        }
+        assert!(is_case("so_close", Case::Snake));
+        assert!(!is_case("soClose", Case::Snake));
+    }
+
+    #[test]
+    fn test_case_names() {
+        for case in Case::ALL {
+            assert_eq!(case.name().parse(), Ok(case));
+        }
+        assert_eq!("screaming-snake".parse(), Ok(Case::ScreamingSnake));
+        assert_eq!("camel".parse(), Ok(Case::Camel));
+        assert_eq!("sPoNgE".parse::<Case>(), Err(UnknownCase("sPoNgE".to_string())));
+    }
+}
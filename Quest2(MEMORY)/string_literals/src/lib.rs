@@ -1,5 +1,8 @@
+mod multi;
+pub use multi::{Match, MatchKind, Options, PatternError, Patterns};
+
 pub fn is_empty(v: &str) -> bool {
-    v == ""
+    v.is_empty()
 }
 
 pub fn is_ascii(v: &str) -> bool {
@@ -11,9 +14,9 @@ pub fn contains(v: &str, pat: &str) -> bool {
 }
 
 pub fn split_at(v: &str, index: usize) -> (&str, &str) {
-    (v.split_at(index))
+    v.split_at(index)
 }
 
-pub fn find(v: &str, pat: char) -> usize {
-    v.find(pat).unwrap_or(100)
+pub fn find(v: &str, pat: char) -> Option<usize> {
+    v.find(pat)
 }
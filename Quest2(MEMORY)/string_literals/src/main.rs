@@ -5,5 +5,141 @@ fn main() {
     println!("{}", is_ascii("rust"));
     println!("{}", contains("rust", "ru"));
     println!("{:?}", split_at("rust", 2));
-    println!("{}", find("rust", 'u'));
-}
\ No newline at end of file
+    println!("{:?}", find("rust", 'u'));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans<'a>(haystack: &'a str, matches: &[Match]) -> Vec<(usize, &'a str)> {
+        matches.iter().map(|m| (m.pattern, &haystack[m.range()])).collect()
+    }
+
+    //* every occurrence of every pattern, found the slow way
+    fn naive(patterns: &[&str], haystack: &str) -> Vec<Match> {
+        let mut matches = Vec::new();
+        for (pattern, p) in patterns.iter().enumerate() {
+            for start in 0..haystack.len() {
+                if haystack.as_bytes()[start..].starts_with(p.as_bytes()) {
+                    matches.push(Match { pattern, start, end: start + p.len() });
+                }
+            }
+        }
+        matches.sort_by_key(|m| (m.start, std::cmp::Reverse(m.end), m.pattern));
+        matches
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("rust", 'u'), Some(1));
+        assert_eq!(find("rust", 'x'), None);
+        assert_eq!(find("héllo", 'l'), Some(3));
+    }
+
+    #[test]
+    fn test_all_matches() {
+        let patterns = Patterns::new(["he", "she", "his", "hers"]).unwrap();
+        let haystack = "ushers";
+        let matches = patterns.find_all(haystack);
+        assert_eq!(spans(haystack, &matches), [(1, "she"), (3, "hers"), (0, "he")]);
+        assert_eq!(matches[0], Match { pattern: 1, start: 1, end: 4 });
+        assert!(patterns.is_match("this"));
+        assert!(!patterns.is_match("nothing"));
+        assert_eq!(patterns.find("nothing"), None);
+    }
+
+    #[test]
+    fn test_against_naive() {
+        let patterns = ["a", "ab", "bab", "bc", "bca", "c", "caa", "aaaa"];
+        let automaton = Patterns::new(patterns).unwrap();
+        for haystack in ["abccab", "aaaaaa", "babcabcaa", "xyz", "", "cbcbcaaaab"] {
+            assert_eq!(automaton.find_all(haystack), naive(&patterns, haystack), "{}", haystack);
+        }
+    }
+
+    #[test]
+    fn test_leftmost_longest() {
+        let options = Options { match_kind: MatchKind::LeftmostLongest, ..Options::default() };
+        let patterns = Patterns::with_options(["abc", "abcd", "b", "cde", "e"], options).unwrap();
+        let haystack = "xabcdefabce";
+        assert_eq!(spans(haystack, &patterns.find_all(haystack)), [(1, "abcd"), (4, "e"), (0, "abc"), (4, "e")]);
+
+        //* a candidate is only settled once nothing further on can start before it
+        let patterns = Patterns::with_options(["bcd", "abcdef", "c", "f"], options).unwrap();
+        for (haystack, expected) in [
+            ("abcdeg", vec![(0, "bcd")]),
+            ("abcdef", vec![(1, "abcdef")]),
+            ("abcdf", vec![(0, "bcd"), (3, "f")]),
+        ] {
+            assert_eq!(spans(haystack, &patterns.find_all(haystack)), expected, "{}", haystack);
+        }
+
+        let runs = Patterns::with_options(["a", "aa", "aaa"], options).unwrap();
+        let long = "a".repeat(100_000) + "b";
+        let matches = runs.find_all(&long);
+        assert_eq!(matches.len(), 33_334);
+        assert!(matches[..33_333].iter().all(|m| m.pattern == 2));
+        assert_eq!(matches[33_333], Match { pattern: 0, start: 99_999, end: 100_000 });
+    }
+
+    #[test]
+    fn test_leftmost_longest_against_naive() {
+        let patterns = ["a", "ab", "bab", "bc", "bca", "c", "caa", "aaaa", "ab"];
+        let options = Options { match_kind: MatchKind::LeftmostLongest, ..Options::default() };
+        let automaton = Patterns::with_options(patterns, options).unwrap();
+        let overlapping = Patterns::new(patterns).unwrap();
+        let mut state = 7u64;
+        for _ in 0..2000 {
+            let haystack: String = (0..12)
+                .map(|_| {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    ['a', 'b', 'c'][(state >> 33) as usize % 3]
+                })
+                .collect();
+            let mut expected = naive(&patterns, &haystack);
+            let mut taken_until = 0;
+            expected.retain(|m| {
+                let keep = m.start >= taken_until;
+                if keep {
+                    taken_until = m.end;
+                }
+                keep
+            });
+            assert_eq!(automaton.find_all(&haystack), expected, "{}", haystack);
+            assert_eq!(automaton.find(&haystack), expected.first().copied(), "{}", haystack);
+            assert_eq!(overlapping.find(&haystack), expected.first().copied(), "{}", haystack);
+        }
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let options = Options { case_insensitive: true, ..Options::default() };
+        let patterns = Patterns::with_options(["error", "WARN", "Timeout"], options).unwrap();
+        let line = "[Warn] ERROR: connection TIMEOUT, error";
+        assert_eq!(
+            spans(line, &patterns.find_all(line)),
+            [(1, "Warn"), (0, "ERROR"), (2, "TIMEOUT"), (0, "error")]
+        );
+        assert!(!Patterns::new(["error"]).unwrap().is_match("ERROR"));
+    }
+
+    #[test]
+    fn test_utf8_ranges() {
+        let patterns = Patterns::new(["été", "😀", "t"]).unwrap();
+        let haystack = "un été 😀!";
+        let matches = patterns.find_all(haystack);
+        assert_eq!(spans(haystack, &matches), [(0, "été"), (2, "t"), (1, "😀")]);
+        assert_eq!(matches[0].range(), 3..8);
+    }
+
+    #[test]
+    fn test_many_patterns() {
+        let keywords: Vec<String> = (0..500).map(|i| format!("key{}", i)).collect();
+        let patterns = Patterns::new(&keywords).unwrap();
+        assert_eq!(patterns.len(), 500);
+        let found: Vec<usize> = patterns.find_all("key42 and key499").iter().map(|m| m.pattern).collect();
+        assert_eq!(found, [42, 4, 499, 49, 4]);
+        assert_eq!(Patterns::new(["a", ""]).unwrap_err(), PatternError::Empty(1));
+    }
+}
@@ -0,0 +1,260 @@
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchKind {
+    //* every occurrence of every pattern, overlapping ones included
+    #[default]
+    All,
+    //* scanning left to right, the longest pattern starting at the leftmost position wins
+    //* and the search carries on after it, so no two matches overlap
+    LeftmostLongest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Options {
+    pub match_kind: MatchKind,
+    //* ASCII letters only: "error" matches "ERROR", but "é" does not match "É"
+    pub case_insensitive: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    //* index of the pattern in the list given to `Patterns::new`
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Match {
+    //* byte range into the haystack, always on char boundaries
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    //* an empty pattern would match between every two bytes
+    Empty(usize),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatternError::Empty(id) => write!(f, "pattern {} is empty", id),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    //* sorted by byte so lookups can binary search
+    next: Vec<(u8, usize)>,
+    //* the state for the longest proper suffix of this one that is also in the trie
+    fail: usize,
+    //* patterns ending here, including the ones reached through `fail`
+    outputs: Vec<usize>,
+    //* length in bytes of the prefix this state stands for
+    depth: usize,
+}
+
+impl State {
+    fn goto(&self, byte: u8) -> Option<usize> {
+        self.next.binary_search_by_key(&byte, |&(b, _)| b).ok().map(|i| self.next[i].1)
+    }
+}
+
+//* Aho–Corasick automaton: a trie of the patterns plus failure links, so one pass over the
+//* haystack finds every pattern, however many there are
+#[derive(Debug, Clone)]
+pub struct Patterns {
+    states: Vec<State>,
+    lens: Vec<usize>,
+    options: Options,
+}
+
+impl Patterns {
+    pub fn new<I, P>(patterns: I) -> Result<Self, PatternError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        Self::with_options(patterns, Options::default())
+    }
+
+    pub fn with_options<I, P>(patterns: I, options: Options) -> Result<Self, PatternError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let mut states = vec![State::default()];
+        let mut lens = Vec::new();
+        for (id, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            if pattern.is_empty() {
+                return Err(PatternError::Empty(id));
+            }
+            let mut current = 0;
+            for &byte in pattern.as_bytes() {
+                let byte = fold(byte, options.case_insensitive);
+                current = match states[current].goto(byte) {
+                    Some(next) => next,
+                    None => {
+                        let depth = states[current].depth + 1;
+                        states.push(State { depth, ..State::default() });
+                        let next = states.len() - 1;
+                        let edges = &mut states[current].next;
+                        let at = edges.partition_point(|&(b, _)| b < byte);
+                        edges.insert(at, (byte, next));
+                        next
+                    }
+                };
+            }
+            states[current].outputs.push(id);
+            lens.push(pattern.len());
+        }
+
+        //* breadth first, so the fail state of every parent is done before its children
+        let mut queue: std::collections::VecDeque<usize> = states[0].next.iter().map(|&(_, s)| s).collect();
+        while let Some(parent) = queue.pop_front() {
+            for (byte, child) in states[parent].next.clone() {
+                let mut fail = states[parent].fail;
+                let target = loop {
+                    if let Some(next) = states[fail].goto(byte) {
+                        break next;
+                    }
+                    if fail == 0 {
+                        break 0;
+                    }
+                    fail = states[fail].fail;
+                };
+                states[child].fail = target;
+                let inherited = states[target].outputs.clone();
+                states[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+        Ok(Patterns { states, lens, options })
+    }
+
+    pub fn len(&self) -> usize {
+        self.lens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lens.is_empty()
+    }
+
+    fn step(&self, mut state: usize, byte: u8) -> usize {
+        let byte = fold(byte, self.options.case_insensitive);
+        loop {
+            if let Some(next) = self.states[state].goto(byte) {
+                return next;
+            }
+            if state == 0 {
+                return 0;
+            }
+            state = self.states[state].fail;
+        }
+    }
+
+    //* stops at the first pattern found, whatever the match kind
+    pub fn is_match(&self, haystack: &str) -> bool {
+        let mut state = 0;
+        haystack.bytes().any(|byte| {
+            state = self.step(state, byte);
+            !self.states[state].outputs.is_empty()
+        })
+    }
+
+    //* matches sorted by start, then longest first, then by pattern id. `MatchKind::All` has to collect
+    //* and sort every overlapping match; `LeftmostLongest` comes out of the scan already in order
+    pub fn find_all(&self, haystack: &str) -> Vec<Match> {
+        let mut matches = Vec::new();
+        if self.options.match_kind == MatchKind::LeftmostLongest {
+            self.leftmost_longest(haystack, |m| {
+                matches.push(m);
+                true
+            });
+            return matches;
+        }
+        let mut state = 0;
+        for (i, byte) in haystack.bytes().enumerate() {
+            state = self.step(state, byte);
+            for &pattern in &self.states[state].outputs {
+                matches.push(Match { pattern, start: i + 1 - self.lens[pattern], end: i + 1 });
+            }
+        }
+        matches.sort_by_key(|m| (m.start, std::cmp::Reverse(m.end), m.pattern));
+        matches
+    }
+
+    //* the first match of `find_all`, which is the same for both match kinds; stops scanning once it is known
+    pub fn find(&self, haystack: &str) -> Option<Match> {
+        let mut first = None;
+        self.leftmost_longest(haystack, |m| {
+            first = Some(m);
+            false
+        });
+        first
+    }
+
+    //* leftmost-longest matches, passed to `emit` as soon as they are settled, until it returns false.
+    //* The best candidate so far is kept until no later match can start at or before it (every match
+    //* still to come starts inside the text the current state stands for), then the scan starts over
+    //* from the root right after it. That re-reads at most one pattern length per match, and nothing
+    //* but the candidate is stored
+    fn leftmost_longest<F: FnMut(Match) -> bool>(&self, haystack: &str, mut emit: F) {
+        let bytes = haystack.as_bytes();
+        let mut state = 0;
+        let mut best: Option<Match> = None;
+        let mut i = 0;
+        loop {
+            let settled = if i == bytes.len() {
+                match best {
+                    Some(b) => b,
+                    None => return,
+                }
+            } else {
+                state = self.step(state, bytes[i]);
+                let end = i + 1;
+                match best {
+                    //* the patterns ending here all start at or after `end - depth`, none of them can beat it
+                    Some(b) if b.start < end - self.states[state].depth => b,
+                    _ => {
+                        for &pattern in &self.states[state].outputs {
+                            let start = end - self.lens[pattern];
+                            //* the ends only grow, so at the same start a later match is a longer one
+                            let better = match best {
+                                None => true,
+                                Some(b) => start < b.start || (start == b.start && (end > b.end || pattern < b.pattern)),
+                            };
+                            if better {
+                                best = Some(Match { pattern, start, end });
+                            }
+                        }
+                        i = end;
+                        continue;
+                    }
+                }
+            };
+            if !emit(settled) {
+                return;
+            }
+            best = None;
+            state = 0;
+            i = settled.end;
+        }
+    }
+}
+
+fn fold(byte: u8, case_insensitive: bool) -> u8 {
+    if case_insensitive {
+        byte.to_ascii_lowercase()
+    } else {
+        byte
+    }
+}
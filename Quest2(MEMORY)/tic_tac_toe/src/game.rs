@@ -0,0 +1,419 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Player {
+    X,
+    O,
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::X => Player::O,
+            Player::O => Player::X,
+        }
+    }
+
+    pub fn symbol(self) -> char {
+        match self {
+            Player::X => 'X',
+            Player::O => 'O',
+        }
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    InProgress { to_move: Player },
+    Won(Player),
+    Draw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    OutOfBounds { row: usize, col: usize },
+    Occupied { row: usize, col: usize },
+    WrongTurn { expected: Player },
+    GameOver,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoveError::OutOfBounds { row, col } => write!(f, "({}, {}) is outside the board", row, col),
+            MoveError::Occupied { row, col } => write!(f, "({}, {}) is already taken", row, col),
+            MoveError::WrongTurn { expected } => write!(f, "it is {}'s turn", expected),
+            MoveError::GameOver => write!(f, "the game is already over"),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+//* why a board can't be the result of a real game (X always starts)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardError {
+    //* size 0 or above `MAX_SIZE`, or k not in 1..=size
+    BadSize { size: usize, k: usize },
+    //* the cells don't fill a size × size board; `found` stops counting at `expected + 1`
+    BadLength { expected: usize, found: usize },
+    InvalidChar(char),
+    //* X must have as many marks as O or exactly one more
+    MoveCount { x: usize, o: usize },
+    BothWon,
+    //* the winner's last move must have completed every line it has, otherwise it won earlier
+    //* and the game went on after that
+    PlayedAfterWin(Player),
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoardError::BadSize { size, k } => write!(f, "can't play {} in a row on a {}x{} board", k, size, size),
+            BoardError::BadLength { expected, found } => write!(f, "expected {} cells, found {}", expected, found),
+            BoardError::InvalidChar(c) => write!(f, "{:?} is not X, O or an empty cell", c),
+            BoardError::MoveCount { x, o } => write!(f, "X has {} marks and O has {}", x, o),
+            BoardError::BothWon => write!(f, "both players have a winning line"),
+            BoardError::PlayedAfterWin(p) => write!(f, "moves were played after {} had won", p),
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+//* the value of a position for the player to move, when both sides play perfectly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub outcome: Outcome,
+    //* every move that keeps `outcome`, in row-major order; empty when the game is over
+    pub best_moves: Vec<(usize, usize)>,
+}
+
+//* an N×N board where the first to get K in a row (across, down or diagonally) wins
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    size: usize,
+    k: usize,
+    cells: Vec<Option<Player>>,
+    to_move: Player,
+    winner: Option<Player>,
+    //* cell indexes in the order they were played, for `undo`
+    history: Vec<usize>,
+}
+
+//* the largest board side accepted, so size * size always fits and a board is at most a million cells
+pub const MAX_SIZE: usize = 1024;
+
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+//* scores above this are forced wins, the remainder tells how many cells were left when it happened
+const WIN: i32 = 1_000_000;
+
+impl Game {
+    pub fn new(size: usize, k: usize) -> Result<Self, BoardError> {
+        if size == 0 || size > MAX_SIZE || k == 0 || k > size {
+            return Err(BoardError::BadSize { size, k });
+        }
+        Ok(Game { size, k, cells: vec![None; size * size], to_move: Player::X, winner: None, history: Vec::new() })
+    }
+
+    pub fn classic() -> Self {
+        Game { size: 3, k: 3, cells: vec![None; 9], to_move: Player::X, winner: None, history: Vec::new() }
+    }
+
+    //* the `tic_tac_toe` table format, with anything in " .#-_" as an empty cell
+    pub fn from_table(table: [[char; 3]; 3]) -> Result<Self, BoardError> {
+        Self::from_cells(3, 3, table.iter().flatten().copied())
+    }
+
+    //* checks that the board can be reached by playing X, O, X, ... from an empty board;
+    //* the history is not known, so `undo` is not available on the result
+    pub fn from_cells<I: IntoIterator<Item = char>>(size: usize, k: usize, cells: I) -> Result<Self, BoardError> {
+        let mut game = Game::new(size, k)?;
+        let expected = size * size;
+        //* one extra cell is enough to tell that there are too many
+        let cells = cells
+            .into_iter()
+            .take(expected + 1)
+            .map(|c| match c {
+                'X' | 'x' => Ok(Some(Player::X)),
+                'O' | 'o' => Ok(Some(Player::O)),
+                ' ' | '.' | '#' | '-' | '_' => Ok(None),
+                _ => Err(BoardError::InvalidChar(c)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if cells.len() != expected {
+            return Err(BoardError::BadLength { expected, found: cells.len() });
+        }
+        game.cells = cells;
+
+        let x = game.cells.iter().filter(|&&c| c == Some(Player::X)).count();
+        let o = game.cells.iter().filter(|&&c| c == Some(Player::O)).count();
+        if x != o && x != o + 1 {
+            return Err(BoardError::MoveCount { x, o });
+        }
+        game.to_move = if x == o { Player::X } else { Player::O };
+        let x_won = game.has_line(Player::X);
+        let o_won = game.has_line(Player::O);
+        game.winner = match (x_won, o_won) {
+            (true, true) => return Err(BoardError::BothWon),
+            (true, false) => Some(Player::X),
+            (false, true) => Some(Player::O),
+            (false, false) => None,
+        };
+        if let Some(winner) = game.winner {
+            //* the winner moved last, and removing that last mark must undo every line it has
+            let last_mover = game.to_move.other();
+            let completes_all = |i: usize| {
+                let mut without = game.clone();
+                without.cells[i] = None;
+                !without.has_line(winner)
+            };
+            if winner != last_mover || !(0..game.cells.len()).any(|i| game.cells[i] == Some(winner) && completes_all(i)) {
+                return Err(BoardError::PlayedAfterWin(winner));
+            }
+        }
+        Ok(game)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<Player> {
+        if row < self.size && col < self.size {
+            self.cells[row * self.size + col]
+        } else {
+            None
+        }
+    }
+
+    pub fn to_move(&self) -> Player {
+        self.to_move
+    }
+
+    pub fn status(&self) -> Status {
+        match self.winner {
+            Some(p) => Status::Won(p),
+            None if self.cells.iter().all(Option::is_some) => Status::Draw,
+            None => Status::InProgress { to_move: self.to_move },
+        }
+    }
+
+    //* empty cells in row-major order, none once someone has won
+    pub fn legal_moves(&self) -> Vec<(usize, usize)> {
+        if self.winner.is_some() {
+            return Vec::new();
+        }
+        (0..self.cells.len()).filter(|&i| self.cells[i].is_none()).map(|i| (i / self.size, i % self.size)).collect()
+    }
+
+    pub fn play(&mut self, player: Player, row: usize, col: usize) -> Result<Status, MoveError> {
+        if !matches!(self.status(), Status::InProgress { .. }) {
+            return Err(MoveError::GameOver);
+        }
+        if player != self.to_move {
+            return Err(MoveError::WrongTurn { expected: self.to_move });
+        }
+        if row >= self.size || col >= self.size {
+            return Err(MoveError::OutOfBounds { row, col });
+        }
+        let i = row * self.size + col;
+        if self.cells[i].is_some() {
+            return Err(MoveError::Occupied { row, col });
+        }
+        self.place(i);
+        Ok(self.status())
+    }
+
+    //* takes back the last move, false when there is nothing to take back
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(i) => {
+                self.cells[i] = None;
+                self.winner = None;
+                self.to_move = self.to_move.other();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn place(&mut self, i: usize) {
+        let player = self.to_move;
+        self.cells[i] = Some(player);
+        self.history.push(i);
+        if self.line_through(i, player) {
+            self.winner = Some(player);
+        }
+        self.to_move = player.other();
+    }
+
+    //* length of the run of `player` marks starting next to `i` and going in (dr, dc)
+    fn run(&self, i: usize, (dr, dc): (isize, isize), player: Player) -> usize {
+        let (mut r, mut c) = ((i / self.size) as isize, (i % self.size) as isize);
+        let mut count = 0;
+        loop {
+            r += dr;
+            c += dc;
+            if r < 0 || c < 0 || r >= self.size as isize || c >= self.size as isize {
+                return count;
+            }
+            if self.cells[r as usize * self.size + c as usize] != Some(player) {
+                return count;
+            }
+            count += 1;
+        }
+    }
+
+    //* does `player` have K in a row going through cell `i`
+    fn line_through(&self, i: usize, player: Player) -> bool {
+        DIRECTIONS
+            .iter()
+            .any(|&(dr, dc)| 1 + self.run(i, (dr, dc), player) + self.run(i, (-dr, -dc), player) >= self.k)
+    }
+
+    fn has_line(&self, player: Player) -> bool {
+        (0..self.cells.len()).any(|i| self.cells[i] == Some(player) && self.line_through(i, player))
+    }
+
+    //* moves near the centre first, they are the best ones most of the time and make the cutoffs kick in sooner
+    fn ordered_moves(&self) -> Vec<usize> {
+        let mut moves: Vec<usize> = (0..self.cells.len()).filter(|&i| self.cells[i].is_none()).collect();
+        let centre = (self.size as isize - 1) as f64 / 2.0;
+        moves.sort_by(|&a, &b| {
+            let dist = |i: usize| ((i / self.size) as f64 - centre).abs() + ((i % self.size) as f64 - centre).abs();
+            dist(a).total_cmp(&dist(b))
+        });
+        moves
+    }
+
+    //* negamax with alpha-beta pruning, scored for the player to move; `depth` None searches to the end
+    fn negamax(&mut self, depth: Option<u32>, mut alpha: i32, beta: i32) -> i32 {
+        let empty = self.cells.iter().filter(|c| c.is_none()).count() as i32;
+        if self.winner.is_some() {
+            //* the previous player just won; sooner wins (more empty cells) score higher
+            return -(WIN + empty);
+        }
+        if empty == 0 {
+            return 0;
+        }
+        if depth == Some(0) {
+            return self.heuristic(self.to_move);
+        }
+        let mut best = i32::MIN + 1;
+        for i in self.ordered_moves() {
+            self.place(i);
+            let score = -self.negamax(depth.map(|d| d - 1), -beta, -alpha);
+            self.undo();
+            best = best.max(score);
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+        best
+    }
+
+    //* every K-long window that only one player has marks in counts for that player, more marks count more
+    fn heuristic(&self, player: Player) -> i32 {
+        let mut score = 0;
+        for i in 0..self.cells.len() {
+            let (r, c) = ((i / self.size) as isize, (i % self.size) as isize);
+            for &(dr, dc) in &DIRECTIONS {
+                let (end_r, end_c) = (r + dr * (self.k as isize - 1), c + dc * (self.k as isize - 1));
+                if end_r < 0 || end_c < 0 || end_r >= self.size as isize || end_c >= self.size as isize {
+                    continue;
+                }
+                let (mut mine, mut theirs) = (0i32, 0i32);
+                for step in 0..self.k as isize {
+                    match self.cells[((r + dr * step) * self.size as isize + c + dc * step) as usize] {
+                        Some(p) if p == player => mine += 1,
+                        Some(_) => theirs += 1,
+                        None => {}
+                    }
+                }
+                match (mine, theirs) {
+                    (m, 0) => score += m * m,
+                    (0, t) => score -= t * t,
+                    _ => {}
+                }
+            }
+        }
+        score
+    }
+
+    //* the move the AI would play, looking `depth` moves ahead (None for a full search, fine for 3×3);
+    //* among equal moves the one closest to the centre wins
+    pub fn best_move(&self, depth: Option<u32>) -> Option<(usize, usize)> {
+        if !matches!(self.status(), Status::InProgress { .. }) {
+            return None;
+        }
+        let mut game = self.clone();
+        let mut best = None;
+        let mut alpha = i32::MIN + 1;
+        for i in game.ordered_moves() {
+            game.place(i);
+            let score = -game.negamax(depth.map(|d| d.saturating_sub(1)), i32::MIN + 1, -alpha);
+            game.undo();
+            if best.is_none() || score > alpha {
+                alpha = score;
+                best = Some((i / self.size, i % self.size));
+            }
+        }
+        best
+    }
+
+    //* searches the whole game tree: perfect play, but only practical when few cells are empty
+    //* (any 3×3 position, or 4×4 late in the game)
+    pub fn solve(&self) -> Solution {
+        let outcome_of = |score: i32| match score {
+            s if s > 0 => Outcome::Win,
+            0 => Outcome::Draw,
+            _ => Outcome::Loss,
+        };
+        let mut game = self.clone();
+        if !matches!(self.status(), Status::InProgress { .. }) {
+            let outcome = outcome_of(game.negamax(None, -WIN * 2, WIN * 2));
+            return Solution { outcome, best_moves: Vec::new() };
+        }
+        let mut scored = Vec::new();
+        for (row, col) in self.legal_moves() {
+            game.place(row * self.size + col);
+            //* a null window around 0 is enough to tell win, draw and loss apart
+            let score = -game.negamax(None, -1, 2);
+            game.undo();
+            scored.push(((row, col), outcome_of(score)));
+        }
+        let outcome = scored.iter().map(|&(_, o)| o).max().unwrap_or(Outcome::Draw);
+        let best_moves = scored.into_iter().filter(|&(_, o)| o == outcome).map(|(m, _)| m).collect();
+        Solution { outcome, best_moves }
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.size) {
+            let line: String = row.iter().map(|c| c.map_or('.', Player::symbol)).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
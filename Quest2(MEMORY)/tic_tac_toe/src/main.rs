@@ -22,4 +22,162 @@ fn main() {
 // tie
 // player O won
 // player X won
-// $
\ No newline at end of file
+// $
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(rows: &[&str], k: usize) -> Result<Game, BoardError> {
+        Game::from_cells(rows.len(), k, rows.concat().chars())
+    }
+
+    #[test]
+    fn test_tic_tac_toe() {
+        assert_eq!(tic_tac_toe([['O', 'X', 'O'], ['O', 'P', 'X'], ['X', '#', 'X']]), "tie");
+        assert_eq!(tic_tac_toe([['X', 'O', 'O'], ['X', 'O', 'O'], ['#', 'O', 'X']]), "player O won");
+        assert_eq!(tic_tac_toe([['O', 'O', 'X'], ['O', 'X', 'O'], ['X', '#', 'X']]), "player X won");
+    }
+
+    #[test]
+    fn test_play() {
+        let mut game = Game::classic();
+        assert_eq!(game.legal_moves().len(), 9);
+        assert_eq!(game.play(Player::O, 0, 0), Err(MoveError::WrongTurn { expected: Player::X }));
+        assert_eq!(game.play(Player::X, 1, 1), Ok(Status::InProgress { to_move: Player::O }));
+        assert_eq!(game.play(Player::O, 1, 1), Err(MoveError::Occupied { row: 1, col: 1 }));
+        assert_eq!(game.play(Player::O, 3, 0), Err(MoveError::OutOfBounds { row: 3, col: 0 }));
+        game.play(Player::O, 0, 0).unwrap();
+        game.play(Player::X, 0, 2).unwrap();
+        game.play(Player::O, 2, 2).unwrap();
+        assert_eq!(game.play(Player::X, 2, 0), Ok(Status::Won(Player::X)));
+        assert_eq!(game.legal_moves(), vec![]);
+        assert_eq!(game.play(Player::O, 0, 1), Err(MoveError::GameOver));
+        assert_eq!(game.to_string(), "O.X\n.X.\nX.O\n");
+        assert!(game.undo());
+        assert_eq!(game.status(), Status::InProgress { to_move: Player::X });
+        assert_eq!(game.legal_moves().len(), 5);
+    }
+
+    #[test]
+    fn test_invalid_boards() {
+        assert_eq!(board(&["XXX", "OOO", "..."], 3).unwrap_err(), BoardError::BothWon);
+        assert_eq!(board(&["XX.", "...", "..."], 3).unwrap_err(), BoardError::MoveCount { x: 2, o: 0 });
+        assert_eq!(board(&["O..", "...", "..."], 3).unwrap_err(), BoardError::MoveCount { x: 0, o: 1 });
+        //* X won, then O kept playing
+        assert_eq!(board(&["XXX", "OO.", "..O"], 3).unwrap_err(), BoardError::PlayedAfterWin(Player::X));
+        //* one move can finish two lines at once, but not two separate ones
+        assert_eq!(board(&["XXX", "OOX", "OOX"], 3).map(|g| g.status()), Ok(Status::Won(Player::X)));
+        assert_eq!(board(&["XXX.", "OO.O", "XXX.", "O.O."], 3).unwrap_err(), BoardError::PlayedAfterWin(Player::X));
+        assert_eq!(board(&["XXXO", "OOO.", "XXXO", "...."], 3).unwrap_err(), BoardError::BothWon);
+        assert_eq!(board(&["X.", ".."], 3).unwrap_err(), BoardError::BadSize { size: 2, k: 3 });
+        assert_eq!(board(&["XOP", "...", "..."], 3).unwrap_err(), BoardError::InvalidChar('P'));
+        assert_eq!(Game::from_cells(3, 3, "XO".chars()).unwrap_err(), BoardError::BadLength { expected: 9, found: 2 });
+        assert_eq!(Game::from_cells(3, 3, std::iter::repeat('.')).unwrap_err(), BoardError::BadLength { expected: 9, found: 10 });
+        let game = Game::from_table([['X', 'O', 'X'], ['#', 'O', '#'], ['#', '#', '#']]).unwrap();
+        assert_eq!(game.to_move(), Player::X);
+    }
+
+    #[test]
+    fn test_reachable_positions() {
+        //* 5478 positions can come up in a game of 3×3 tic-tac-toe, empty board included
+        let valid = (0..3usize.pow(9))
+            .filter(|&n| {
+                let cells = (0..9).map(|i| ['.', 'X', 'O'][n / 3usize.pow(i) % 3]);
+                Game::from_cells(3, 3, cells).is_ok()
+            })
+            .count();
+        assert_eq!(valid, 5478);
+    }
+
+    #[test]
+    fn test_game_count() {
+        //* 255168 different games, counting every move order
+        fn count(game: &mut Game) -> u32 {
+            let moves = game.legal_moves();
+            if moves.is_empty() {
+                return 1;
+            }
+            let mut total = 0;
+            for (row, col) in moves {
+                game.play(game.to_move(), row, col).unwrap();
+                total += count(game);
+                game.undo();
+            }
+            total
+        }
+        assert_eq!(count(&mut Game::classic()), 255168);
+    }
+
+    #[test]
+    fn test_n_by_n() {
+        let mut game = Game::new(5, 4).unwrap();
+        for (player, row, col) in [(Player::X, 0, 1), (Player::O, 4, 4), (Player::X, 1, 2), (Player::O, 4, 3), (Player::X, 2, 3)] {
+            assert!(matches!(game.play(player, row, col), Ok(Status::InProgress { .. })));
+        }
+        game.play(Player::O, 0, 0).unwrap();
+        assert_eq!(game.play(Player::X, 3, 4), Ok(Status::Won(Player::X)));
+        assert_eq!(board(&["X...", ".X..", "..X.", "OO.."], 3).map(|g| g.status()), Ok(Status::Won(Player::X)));
+        assert!(Game::new(0, 0).is_err());
+        assert_eq!(Game::new(MAX_SIZE, 5).map(|g| g.legal_moves().len()), Ok(MAX_SIZE * MAX_SIZE));
+        assert_eq!(Game::new(MAX_SIZE + 1, 5), Err(BoardError::BadSize { size: MAX_SIZE + 1, k: 5 }));
+        assert_eq!(Game::new(usize::MAX, 3), Err(BoardError::BadSize { size: usize::MAX, k: 3 }));
+        assert_eq!(
+            Game::from_cells(usize::MAX, 3, std::iter::repeat('.')).unwrap_err(),
+            BoardError::BadSize { size: usize::MAX, k: 3 }
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let solution = Game::classic().solve();
+        assert_eq!(solution.outcome, Outcome::Draw);
+        assert_eq!(solution.best_moves.len(), 9);
+
+        //* X to move can win at once with (0, 2)
+        let game = board(&["XX.", "OO.", "..."], 3).unwrap();
+        assert_eq!(game.solve(), Solution { outcome: Outcome::Win, best_moves: vec![(0, 2)] });
+        assert_eq!(game.best_move(None), Some((0, 2)));
+        //* O took the centre against the corner, it holds the draw
+        let game = board(&["X..", ".O.", "..X"], 3).unwrap();
+        assert_eq!(game.solve().outcome, Outcome::Draw);
+        //* O answered the corner with the edge next to it and is lost
+        let game = board(&["XO.", "...", "..."], 3).unwrap();
+        assert_eq!(game.solve().outcome, Outcome::Win);
+        let game = board(&["XXX", "OO.", "..."], 3).unwrap();
+        assert_eq!(game.solve(), Solution { outcome: Outcome::Loss, best_moves: vec![] });
+    }
+
+    #[test]
+    fn test_ai_never_loses() {
+        //* the AI plays O against every possible sequence of X moves
+        fn check(game: &mut Game) {
+            for (row, col) in game.legal_moves() {
+                game.play(Player::X, row, col).unwrap();
+                assert_ne!(game.status(), Status::Won(Player::X), "\n{}", game);
+                if let Some((r, c)) = game.best_move(None) {
+                    game.play(Player::O, r, c).unwrap();
+                    check(game);
+                    game.undo();
+                }
+                game.undo();
+            }
+        }
+        check(&mut Game::classic());
+
+        let mut game = Game::classic();
+        while let Some((row, col)) = game.best_move(None) {
+            game.play(game.to_move(), row, col).unwrap();
+        }
+        assert_eq!(game.status(), Status::Draw);
+    }
+
+    #[test]
+    fn test_depth_limited_ai() {
+        //* 4 in a row on 6×6: O has to block the only open end of X's three
+        let mut game = board(&["......", "OXXX..", ".O....", "......", "......", "......"], 4).unwrap();
+        assert_eq!(game.best_move(Some(2)), Some((1, 4)));
+        //* and X, if O looks elsewhere, takes the win
+        game.play(Player::O, 5, 5).unwrap();
+        assert_eq!(game.best_move(Some(2)), Some((1, 4)));
+    }
+}
@@ -1,18 +1,25 @@
+mod percent;
+mod query;
+pub use percent::{percent_decode, percent_encode, Component, DecodeError, DecodeErrorKind};
+pub use query::QueryString;
+
+//* encodes `s` to be used as a URL path: spaces, '%', non-ASCII and the like become %XX
 pub fn to_url(s: &str) -> String {
-    s.replace(" ", "%20")
+    percent_encode(s, Component::Path)
 }
 
-// or :
+// or, without the Component table (same result as percent_encode(s, Component::Path)):
 
 /*
     pub fn to_url(s: &str) -> String {
     let mut result = String::new();
 
-    for ch in s.chars() {
-        if ch == ' ' {
-            result.push_str("%20");
-        }else {
-            result.push(ch);
+    // working on bytes encodes a non-ASCII char as one %XX per UTF-8 byte, like percent_encode does
+    for b in s.bytes() {
+        if b.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@/".contains(&b) {
+            result.push(b as char);
+        } else {
+            result.push_str(&format!("%{:02X}", b));
         }
     }
     result
@@ -3,4 +3,80 @@ use to_url::*;
 fn main() {
     let s = "Hello, world!";
     println!("'{}' parsed as an URL becomes '{}'", s, to_url(s));
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_url() {
+        assert_eq!(to_url("Hello, world!"), "Hello,%20world!");
+        assert_eq!(to_url("/docs/café 100%.html"), "/docs/caf%C3%A9%20100%25.html");
+        assert_eq!(to_url("a?b#c"), "a%3Fb%23c");
+    }
+
+    #[test]
+    fn test_components() {
+        let s = "a/b?c=d&e+f#g:h@i é";
+        assert_eq!(percent_encode(s, Component::Path), "a/b%3Fc=d&e+f%23g:h@i%20%C3%A9");
+        assert_eq!(percent_encode(s, Component::PathSegment), "a%2Fb%3Fc=d&e+f%23g:h@i%20%C3%A9");
+        assert_eq!(percent_encode(s, Component::Query), "a/b?c=d&e+f%23g:h@i%20%C3%A9");
+        assert_eq!(percent_encode(s, Component::QueryParam), "a/b?c%3Dd%26e%2Bf%23g:h@i%20%C3%A9");
+        assert_eq!(percent_encode(s, Component::Fragment), "a/b?c=d&e+f%23g:h@i%20%C3%A9");
+        assert_eq!(percent_encode(s, Component::UserInfo), "a%2Fb%3Fc=d&e+f%23g:h%40i%20%C3%A9");
+        assert_eq!(percent_encode("-._~AZaz09", Component::UserInfo), "-._~AZaz09");
+        assert_eq!(percent_encode("😀", Component::Path), "%F0%9F%98%80");
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(percent_decode("caf%C3%A9%20100%25"), Ok("café 100%".to_string()));
+        assert_eq!(percent_decode("a+b%2b"), Ok("a+b+".to_string()));
+        assert_eq!(percent_decode("%e2%82%ac"), Ok("€".to_string()));
+        let err = |position, kind| Err(DecodeError { position, kind });
+        assert_eq!(percent_decode("abc%4"), err(3, DecodeErrorKind::Truncated));
+        assert_eq!(percent_decode("%"), err(0, DecodeErrorKind::Truncated));
+        assert_eq!(percent_decode("a%G1"), err(1, DecodeErrorKind::InvalidHex));
+        assert_eq!(percent_decode("%+1"), err(0, DecodeErrorKind::InvalidHex));
+        assert_eq!(percent_decode("ok%C3%28"), err(2, DecodeErrorKind::InvalidUtf8));
+        assert_eq!(percent_decode("%E2%82"), err(0, DecodeErrorKind::InvalidUtf8));
+        for s in ["Hello, world!", "/a b/ç/100%", "?&=+#@:"] {
+            for c in [Component::Path, Component::PathSegment, Component::QueryParam, Component::UserInfo] {
+                assert_eq!(percent_decode(&percent_encode(s, c)).as_deref(), Ok(s));
+            }
+        }
+    }
+
+    #[test]
+    fn test_query_string() {
+        let query = QueryString::parse("?tag=rust&q=hello+world&tag=url&empty=&flag&&x=%26%3D").unwrap();
+        assert_eq!(query.get("q"), Some("hello world"));
+        assert_eq!(query.get_all("tag"), ["rust", "url"]);
+        assert_eq!(query.get("flag"), Some(""));
+        assert_eq!(query.get("x"), Some("&="));
+        assert_eq!(query.get("missing"), None);
+        let keys: Vec<&str> = query.pairs().iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["tag", "q", "tag", "empty", "flag", "x"]);
+        assert_eq!(query.to_string(), "tag=rust&q=hello%20world&tag=url&empty=&flag=&x=%26%3D");
+        assert_eq!(QueryString::parse(&query.to_string()), Ok(query));
+
+        let err = QueryString::parse("a=1&bad=%zz").unwrap_err();
+        assert_eq!((err.position, err.kind), (8, DecodeErrorKind::InvalidHex));
+        let err = QueryString::parse("?a=1&b%=2").unwrap_err();
+        assert_eq!((err.position, err.kind), (6, DecodeErrorKind::Truncated));
+    }
+
+    #[test]
+    fn test_query_builder() {
+        let mut query = QueryString::new();
+        query.append("q", "a+b c").append("page", "1").append("page", "2").append("lang", "fr");
+        assert_eq!(query.to_string(), "q=a%2Bb%20c&page=1&page=2&lang=fr");
+        query.set("page", "3");
+        assert_eq!(query.to_string(), "q=a%2Bb%20c&page=3&lang=fr");
+        assert_eq!(query.remove("q"), 1);
+        assert_eq!(query.len(), 2);
+        let built: QueryString = [("k", "v"), ("k", "w")].into_iter().collect();
+        assert_eq!(built.to_string(), "k=v&k=w");
+        assert!(QueryString::parse("").unwrap().is_empty());
+    }
+}
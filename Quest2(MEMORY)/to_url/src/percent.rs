@@ -0,0 +1,118 @@
+use std::fmt;
+
+//* the parts of a URI, each with its own set of characters that may appear unescaped (RFC 3986, section 3)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Component {
+    //* a whole path, '/' is kept
+    Path,
+    //* one path segment, '/' is escaped
+    PathSegment,
+    Query,
+    //* a key or value inside a query string, so '&', '=', '+' and ';' are escaped too
+    QueryParam,
+    Fragment,
+    UserInfo,
+}
+
+impl Component {
+    pub fn allows(self, c: char) -> bool {
+        let unreserved = c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~');
+        let sub_delim = matches!(c, '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=');
+        let pchar = unreserved || sub_delim || c == ':' || c == '@';
+        match self {
+            Component::Path => pchar || c == '/',
+            Component::PathSegment => pchar,
+            Component::Query | Component::Fragment => pchar || c == '/' || c == '?',
+            Component::QueryParam => (pchar || c == '/' || c == '?') && !matches!(c, '&' | '=' | '+' | ';'),
+            Component::UserInfo => unreserved || sub_delim || c == ':',
+        }
+    }
+}
+
+//* every char the component doesn't allow is written as %XX for each of its UTF-8 bytes;
+//* '%' is never allowed, so text that already holds escapes gets them escaped again
+pub fn percent_encode(s: &str, component: Component) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if component.allows(c) {
+            out.push(c);
+        } else {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                out.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+    //* a '%' without two chars after it
+    Truncated,
+    //* a '%' followed by something that isn't two hex digits
+    InvalidHex,
+    //* the escapes decode to bytes that are not UTF-8
+    InvalidUtf8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    //* byte offset in the input of the '%' (or char) where decoding went wrong
+    pub position: usize,
+    pub kind: DecodeErrorKind,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            DecodeErrorKind::Truncated => write!(f, "incomplete escape")?,
+            DecodeErrorKind::InvalidHex => write!(f, "escape is not two hex digits")?,
+            DecodeErrorKind::InvalidUtf8 => write!(f, "escapes don't decode to UTF-8")?,
+        }
+        write!(f, " at byte {}", self.position)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+pub fn percent_decode(s: &str) -> Result<String, DecodeError> {
+    decode(s, false)
+}
+
+//* `plus_as_space` is for query strings, where a '+' stands for a space
+pub(crate) fn decode(s: &str, plus_as_space: bool) -> Result<String, DecodeError> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    //* where each output byte came from, to report UTF-8 errors at the right place
+    let mut origin = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = bytes.get(i + 1..i + 3).ok_or(DecodeError { position: i, kind: DecodeErrorKind::Truncated })?;
+                let digit = |b: u8| (b as char).to_digit(16);
+                match (digit(hex[0]), digit(hex[1])) {
+                    (Some(hi), Some(lo)) => out.push((hi * 16 + lo) as u8),
+                    _ => return Err(DecodeError { position: i, kind: DecodeErrorKind::InvalidHex }),
+                }
+                origin.push(i);
+                i += 3;
+            }
+            b'+' if plus_as_space => {
+                out.push(b' ');
+                origin.push(i);
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                origin.push(i);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).map_err(|e| DecodeError {
+        position: origin[e.utf8_error().valid_up_to()],
+        kind: DecodeErrorKind::InvalidUtf8,
+    })
+}
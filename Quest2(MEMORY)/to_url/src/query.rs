@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::percent::{decode, percent_encode, Component, DecodeError};
+
+//* "a=1&b=2&a=3" as a list of pairs: order and repeated keys are kept as they were
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryString {
+    pairs: Vec<(String, String)>,
+}
+
+impl QueryString {
+    pub fn new() -> Self {
+        QueryString { pairs: Vec::new() }
+    }
+
+    //* accepts a leading '?', skips empty pairs ("a=1&&b=2") and reads a key without '=' as
+    //* having an empty value; '+' is a space, as browsers send it
+    pub fn parse(s: &str) -> Result<Self, DecodeError> {
+        let (offset, s) = match s.strip_prefix('?') {
+            Some(rest) => (1, rest),
+            None => (0, s),
+        };
+        let mut query = QueryString::new();
+        let mut start = offset;
+        for pair in s.split('&') {
+            if !pair.is_empty() {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                let at = |e: DecodeError, base: usize| DecodeError { position: e.position + base, ..e };
+                let key = decode(key, true).map_err(|e| at(e, start))?;
+                let value = decode(value, true).map_err(|e| at(e, start + key_len(pair)))?;
+                query.pairs.push((key, value));
+            }
+            start += pair.len() + 1;
+        }
+        Ok(query)
+    }
+
+    //* adds a pair at the end, even when the key is already there
+    pub fn append(&mut self, key: &str, value: &str) -> &mut Self {
+        self.pairs.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    //* replaces every pair with this key by a single one, at the place of the first
+    pub fn set(&mut self, key: &str, value: &str) -> &mut Self {
+        match self.pairs.iter().position(|(k, _)| k == key) {
+            Some(i) => {
+                self.pairs[i].1 = value.to_string();
+                let mut seen = 0;
+                self.pairs.retain(|(k, _)| {
+                    seen += (k == key) as usize;
+                    k != key || seen == 1
+                });
+            }
+            None => {
+                self.append(key, value);
+            }
+        }
+        self
+    }
+
+    //* the first value for the key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    pub fn get_all(&self, key: &str) -> Vec<&str> {
+        self.pairs.iter().filter(|(k, _)| k == key).map(|(_, v)| v.as_str()).collect()
+    }
+
+    //* removes every pair with the key, returns how many there were
+    pub fn remove(&mut self, key: &str) -> usize {
+        let before = self.pairs.len();
+        self.pairs.retain(|(k, _)| k != key);
+        before - self.pairs.len()
+    }
+
+    pub fn pairs(&self) -> &[(String, String)] {
+        &self.pairs
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
+
+//* where the value starts inside "key=value"
+fn key_len(pair: &str) -> usize {
+    pair.find('=').map_or(pair.len(), |i| i + 1)
+}
+
+//* "a=1&b=x%20y", without the leading '?'; a pair with an empty value is still written "key="
+impl fmt::Display for QueryString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (key, value)) in self.pairs.iter().enumerate() {
+            if i > 0 {
+                write!(f, "&")?;
+            }
+            write!(f, "{}={}", percent_encode(key, Component::QueryParam), percent_encode(value, Component::QueryParam))?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> FromIterator<(K, V)> for QueryString {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let pairs = iter.into_iter().map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string())).collect();
+        QueryString { pairs }
+    }
+}
@@ -1,14 +1,22 @@
 //* recommendation (⚠️): use `Colorful Comments` extension for better readability of the comments in this file7
 
-pub fn sum(a: &[i32]) -> i32 {
+mod sum;
+pub use sum::{
+    checked_sum, chunked_sum, kahan_sum, naive_sum, neumaier_sum, saturating_sum, widening_sum, wrapping_sum, Float,
+    SumInt, Summable, Widen,
+};
+
+//* integers panic if the sum doesn't fit, debug or release; floats are summed with `neumaier_sum`
+//* see checked_sum / saturating_sum / widening_sum / wrapping_sum for the other ways to deal with overflow
+pub fn sum<T: Summable>(a: &[T]) -> T {
     // let mut sum = 0;
     // for &val in a {
     //     sum += val;
     // }
     // sum
 
-    // or using iterator
-    a.iter().sum()
+    // or using iterator : a.iter().sum(), but it wraps around on overflow in release builds
+    T::total(a)
 }
 
 pub fn thirtytwo_tens() -> [i32; 32] {
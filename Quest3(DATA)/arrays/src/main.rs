@@ -15,6 +15,107 @@ fn main() {
     );
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum() {
+        let a = (1..=10).collect::<Vec<i32>>();
+        assert_eq!(sum(&a), 55);
+        assert_eq!(sum(&[5; 10]), 50);
+        assert_eq!(sum(&thirtytwo_tens()), 320);
+        assert_eq!(sum::<u8>(&[]), 0);
+        assert_eq!(sum(&[1.5f32, 2.25]), 3.75);
+        assert_eq!(sum(&[u64::MAX - 1, 1]), u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "sum of 2 i32 values overflowed")]
+    fn test_sum_overflow_panics() {
+        sum(&[i32::MAX, 1]);
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        let a = [i32::MAX, 1, -2];
+        assert_eq!(checked_sum(&a), None);
+        assert_eq!(checked_sum(&[i32::MAX, -2, 1]), Some(i32::MAX - 1));
+        assert_eq!(saturating_sum(&a), i32::MAX - 2);
+        assert_eq!(wrapping_sum(&a), i32::MAX - 1);
+        assert_eq!(widening_sum(&a), i32::MAX as i128 - 1);
+        assert_eq!(saturating_sum(&[-100i8, -100, 50]), -78);
+        assert_eq!(checked_sum(&[200u8, 56]), None);
+
+        let big = vec![u32::MAX; 1001];
+        assert_eq!(widening_sum(&big), u32::MAX as u128 * 1001);
+        let big = vec![i64::MIN; 19];
+        assert_eq!(widening_sum(&big), i64::MIN as i128 * 19);
+    }
+
+    #[test]
+    fn test_naive_loses_precision() {
+        //* 0.1 is not exact in binary, and once the sum is large each add drops more of it
+        let tenths = vec![0.1f32; 10_000_000];
+        let naive = naive_sum(&tenths);
+        assert!((naive - 1_000_000.0).abs() > 50_000.0, "{}", naive);
+        assert!((kahan_sum(&tenths) - 1_000_000.0).abs() < 1.0);
+        //* Neumaier's compensation is itself a plain f32 sum of 10 million error terms, it drifts too
+        let neumaier = neumaier_sum(&tenths);
+        assert!((neumaier - 1_000_000.0).abs() < 5_000.0, "{}", neumaier);
+        //* which is why `sum` adds f32 values up in f64
+        assert!((sum(&tenths) - 1_000_000.0).abs() < 1.0);
+        let chunked = chunked_sum(&tenths);
+        assert!((chunked - 1_000_000.0).abs() < (naive - 1_000_000.0).abs());
+
+        //* small terms next to a huge one vanish entirely unless they are compensated
+        let a = [1.0f64, 1e100, 1.0, -1e100];
+        assert_eq!(naive_sum(&a), 0.0);
+        assert_eq!(kahan_sum(&a), 0.0);
+        assert_eq!(neumaier_sum(&a), 2.0);
+        assert_eq!(sum(&a), 2.0);
+
+        let a = [1e16f64, 1.0, 1.0, 1.0, 1.0];
+        assert_eq!(naive_sum(&a), 1e16);
+        assert_eq!(kahan_sum(&a), 1e16 + 4.0);
+        assert_eq!(neumaier_sum(&a), 1e16 + 4.0);
+    }
+
+    #[test]
+    fn test_infinities() {
+        let inf = f64::INFINITY;
+        for a in [&[inf][..], &[1.0, inf, 2.0], &[f64::MAX, f64::MAX], &[f64::MAX, 1e300, -1.0]] {
+            assert_eq!(naive_sum(a), inf, "{:?}", a);
+            assert_eq!(kahan_sum(a), inf, "{:?}", a);
+            assert_eq!(neumaier_sum(a), inf, "{:?}", a);
+            assert_eq!(sum(a), inf, "{:?}", a);
+        }
+        for a in [&[-inf][..], &[-inf, 1.0], &[f64::MIN, -1e300]] {
+            assert_eq!(kahan_sum(a), -inf, "{:?}", a);
+            assert_eq!(neumaier_sum(a), -inf, "{:?}", a);
+            assert_eq!(sum(a), -inf, "{:?}", a);
+        }
+        assert!(sum(&[inf, -inf]).is_nan());
+        assert!(sum(&[1.0, f64::NAN]).is_nan());
+
+        assert_eq!(sum(&[f32::INFINITY]), f32::INFINITY);
+        assert_eq!(sum(&[1.0, f32::NEG_INFINITY]), f32::NEG_INFINITY);
+        //* fine in the f64 accumulator, out of range once rounded back to f32
+        assert_eq!(sum(&[f32::MAX, f32::MAX]), f32::INFINITY);
+        assert_eq!(kahan_sum(&[f32::MAX, f32::MAX, 1.0]), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_chunked_sum() {
+        for len in [0, 1, 7, 8, 9, 17, 100] {
+            let a: Vec<f64> = (0..len).map(|i| i as f64).collect();
+            assert_eq!(chunked_sum(&a), (len * len.max(1) - len) as f64 / 2.0, "len {}", len);
+            let ints: Vec<i16> = (0..len as i16).collect();
+            assert_eq!(widening_sum(&ints), (0..len as i128).sum::<i128>());
+        }
+    }
+}
+
 /*
     * Q & A:
     * Q1. What does (1..=10).collect::<Vec<i32>>(); do?
@@ -0,0 +1,202 @@
+use std::ops::{Add, Sub};
+
+//* integer types: the sum of a slice is checked, saturating or wrapping on overflow
+pub trait SumInt: Copy {
+    const ZERO: Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn saturating_add(self, other: Self) -> Self;
+    fn wrapping_add(self, other: Self) -> Self;
+}
+
+//* integers summed in i128 / u128, where a whole slice can be added up without any overflow
+pub trait Widen: SumInt {
+    type Wide: Copy + Add<Output = Self::Wide>;
+    const WIDE_ZERO: Self::Wide;
+    fn widen(self) -> Self::Wide;
+}
+
+macro_rules! impl_sum_int {
+    ($($t:ty),*) => {$(
+        impl SumInt for $t {
+            const ZERO: Self = 0;
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+            fn saturating_add(self, other: Self) -> Self {
+                <$t>::saturating_add(self, other)
+            }
+            fn wrapping_add(self, other: Self) -> Self {
+                <$t>::wrapping_add(self, other)
+            }
+        }
+    )*};
+}
+
+//* a slice has fewer than 2^63 elements and each one is below 2^64 in magnitude, so the sum stays
+//* below 2^127. A 64-bit wide type is not enough: 2^32 u32::MAX values (16 GiB) already overflow a u64
+macro_rules! impl_widen {
+    ($($t:ty => $w:ty),*) => {$(
+        impl Widen for $t {
+            type Wide = $w;
+            const WIDE_ZERO: $w = 0;
+            fn widen(self) -> $w {
+                self as $w
+            }
+        }
+    )*};
+}
+
+impl_sum_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_widen!(i8 => i128, i16 => i128, i32 => i128, i64 => i128, isize => i128, u8 => u128, u16 => u128, u32 => u128, u64 => u128, usize => u128);
+
+pub trait Float: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    fn abs(self) -> Self;
+    fn is_finite(self) -> bool;
+}
+
+impl Float for f32 {
+    const ZERO: Self = 0.0;
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+}
+
+impl Float for f64 {
+    const ZERO: Self = 0.0;
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
+
+//* what `sum` does for each type: integers panic on overflow (in release builds too), floats use Neumaier,
+//* in f64 for f32 values: with millions of terms the f32 compensation loses precision too
+pub trait Summable: Copy {
+    fn total(a: &[Self]) -> Self;
+}
+
+macro_rules! impl_summable_int {
+    ($($t:ty),*) => {$(
+        impl Summable for $t {
+            fn total(a: &[Self]) -> Self {
+                match checked_sum(a) {
+                    Some(total) => total,
+                    None => panic!("sum of {} {} values overflowed", a.len(), stringify!($t)),
+                }
+            }
+        }
+    )*};
+}
+
+impl_summable_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Summable for f32 {
+    fn total(a: &[Self]) -> Self {
+        let mut sum = 0.0f64;
+        let mut compensation = 0.0f64;
+        for &x in a {
+            neumaier_step(&mut sum, &mut compensation, x as f64);
+        }
+        (sum + compensation) as f32
+    }
+}
+
+impl Summable for f64 {
+    fn total(a: &[Self]) -> Self {
+        neumaier_sum(a)
+    }
+}
+
+pub fn checked_sum<T: SumInt>(a: &[T]) -> Option<T> {
+    a.iter().try_fold(T::ZERO, |acc, &x| acc.checked_add(x))
+}
+
+//* clamps at the type's MIN/MAX as soon as it gets there, so [MAX, 1, -1] gives MAX - 1
+pub fn saturating_sum<T: SumInt>(a: &[T]) -> T {
+    a.iter().fold(T::ZERO, |acc, &x| acc.saturating_add(x))
+}
+
+pub fn wrapping_sum<T: SumInt>(a: &[T]) -> T {
+    a.iter().fold(T::ZERO, |acc, &x| acc.wrapping_add(x))
+}
+
+//* the exact sum, in the wider type
+pub fn widening_sum<T: Widen>(a: &[T]) -> T::Wide {
+    let mut lanes = [T::WIDE_ZERO; LANES];
+    let chunks = a.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (lane, &x) in lanes.iter_mut().zip(chunk) {
+            *lane = *lane + x.widen();
+        }
+    }
+    let total = rest.iter().fold(T::WIDE_ZERO, |acc, &x| acc + x.widen());
+    lanes.iter().fold(total, |acc, &lane| acc + lane)
+}
+
+//* left to right, one add at a time: every add rounds, and the errors pile up
+pub fn naive_sum<F: Float>(a: &[F]) -> F {
+    a.iter().fold(F::ZERO, |acc, &x| acc + x)
+}
+
+//* Kahan: keeps the low-order bits lost by each add in `compensation` and feeds them back in.
+//* Once the sum is infinite (or NaN) there are no low-order bits left: `t - sum` would be inf - inf
+pub fn kahan_sum<F: Float>(a: &[F]) -> F {
+    let mut sum = F::ZERO;
+    let mut compensation = F::ZERO;
+    for &x in a {
+        let y = x - compensation;
+        let t = sum + y;
+        compensation = if t.is_finite() { (t - sum) - y } else { F::ZERO };
+        sum = t;
+    }
+    sum
+}
+
+//* Neumaier: like Kahan, but also right when the new term is bigger than the running sum,
+//* e.g. [1.0, 1e100, 1.0, -1e100] gives 2.0 where Kahan and the naive sum give 0.0
+pub fn neumaier_sum<F: Float>(a: &[F]) -> F {
+    let mut sum = F::ZERO;
+    let mut compensation = F::ZERO;
+    for &x in a {
+        neumaier_step(&mut sum, &mut compensation, x);
+    }
+    sum + compensation
+}
+
+//* an infinite `t` (an infinite term, or an overflow) is the result as it is, compensating it gives NaN
+fn neumaier_step<F: Float>(sum: &mut F, compensation: &mut F, x: F) {
+    let t = *sum + x;
+    if !t.is_finite() {
+        *compensation = F::ZERO;
+    } else if sum.abs() >= x.abs() {
+        *compensation = *compensation + ((*sum - t) + x);
+    } else {
+        *compensation = *compensation + ((x - t) + *sum);
+    }
+    *sum = t;
+}
+
+const LANES: usize = 8;
+
+//* LANES independent running sums: the adds of one chunk don't depend on each other, so the compiler
+//* can turn the inner loop into SIMD instructions (it won't for `naive_sum`, reordering float adds
+//* changes the result). It is also a bit more precise than `naive_sum`, each lane adds up fewer terms
+pub fn chunked_sum<F: Float>(a: &[F]) -> F {
+    let mut lanes = [F::ZERO; LANES];
+    let chunks = a.chunks_exact(LANES);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        for (lane, &x) in lanes.iter_mut().zip(chunk) {
+            *lane = *lane + x;
+        }
+    }
+    let total = naive_sum(rest);
+    lanes.iter().fold(total, |acc, &lane| acc + lane)
+}
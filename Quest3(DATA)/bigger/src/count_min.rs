@@ -0,0 +1,94 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::SketchError;
+
+//* Count-Min sketch: `depth` rows of `width` counters, each key adds to one counter per row and its
+//* estimate is the smallest of them. Estimates are never below the true count; with N the total
+//* added, each one is above it by more than e / width * N with probability at most e^-depth
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountMin {
+    width: usize,
+    depth: usize,
+    table: Vec<u64>,
+    total: u64,
+}
+
+impl CountMin {
+    pub fn new(width: usize, depth: usize) -> Result<Self, SketchError> {
+        if width == 0 || depth == 0 {
+            return Err(SketchError::EmptyTable);
+        }
+        let cells = width.checked_mul(depth).ok_or(SketchError::TooLarge)?;
+        let mut table = Vec::new();
+        table.try_reserve_exact(cells).map_err(|_| SketchError::TooLarge)?;
+        table.resize(cells, 0);
+        Ok(CountMin { width, depth, table, total: 0 })
+    }
+
+    //* the smallest table where an estimate is off by more than epsilon * N with probability below delta
+    pub fn with_error(epsilon: f64, delta: f64) -> Result<Self, SketchError> {
+        for p in [epsilon, delta] {
+            if !(p > 0.0 && p < 1.0) {
+                return Err(SketchError::BadParameter(p));
+            }
+        }
+        //* a tiny epsilon asks for more counters than a usize can count, the cast would saturate
+        let width = (std::f64::consts::E / epsilon).ceil();
+        if width >= usize::MAX as f64 {
+            return Err(SketchError::TooLarge);
+        }
+        let depth = (1.0 / delta).ln().ceil().max(1.0) as usize;
+        Self::new(width as usize, depth)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    //* sum of every count added so far, stuck at u64::MAX like the counters
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    //* one hash split in two gives every row its own index (h1 + row * h2), as good as `depth`
+    //* independent hashes for this use (Kirsch & Mitzenmacher)
+    fn cells<K: Hash + ?Sized>(&self, key: &K) -> impl Iterator<Item = usize> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+        let (h1, h2) = (hash & 0xFFFF_FFFF, (hash >> 32) | 1);
+        let width = self.width;
+        (0..self.depth).map(move |row| row * width + (h1.wrapping_add(row as u64 * h2) % width as u64) as usize)
+    }
+
+    //* returns the new estimate for the key
+    pub fn add<K: Hash + ?Sized>(&mut self, key: &K, count: u64) -> u64 {
+        self.total = self.total.saturating_add(count);
+        let mut estimate = u64::MAX;
+        //* `cells` only borrows the width and depth it copied, not the table
+        for i in self.cells(key) {
+            self.table[i] = self.table[i].saturating_add(count);
+            estimate = estimate.min(self.table[i]);
+        }
+        estimate
+    }
+
+    pub fn estimate<K: Hash + ?Sized>(&self, key: &K) -> u64 {
+        self.cells(key).map(|i| self.table[i]).min().unwrap_or(0)
+    }
+
+    //* e / width * total: how far above the true count an estimate can be, except with probability e^-depth
+    pub fn error_bound(&self) -> u64 {
+        (std::f64::consts::E / self.width as f64 * self.total as f64).ceil() as u64
+    }
+
+    //* the chance that a given estimate is further off than `error_bound`
+    pub fn failure_probability(&self) -> f64 {
+        (-(self.depth as f64)).exp()
+    }
+}
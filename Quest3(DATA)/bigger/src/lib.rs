@@ -1,7 +1,42 @@
 use std::collections::HashMap;
+use std::fmt;
 
-pub fn bigger(h: HashMap<&str, i32>) -> i32 {
-    h.into_values()        
-        .max()
-        .unwrap()
+mod count_min;
+mod misra_gries;
+mod top_k;
+pub use count_min::CountMin;
+pub use misra_gries::MisraGries;
+pub use top_k::{top_k, Ties};
+
+//* shared by both sketches
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SketchError {
+    //* a Count-Min width or depth, or a Misra–Gries capacity, of 0
+    EmptyTable,
+    //* epsilon and delta must be in (0, 1)
+    BadParameter(f64),
+    //* the counters don't fit in memory
+    TooLarge,
+}
+
+impl fmt::Display for SketchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SketchError::EmptyTable => write!(f, "the sketch needs at least one counter"),
+            SketchError::BadParameter(p) => write!(f, "{} is not between 0 and 1", p),
+            SketchError::TooLarge => write!(f, "the table is too large to allocate"),
+        }
+    }
+}
+
+impl std::error::Error for SketchError {}
+
+//* None for an empty map, so it can't be mistaken for a real 0
+pub fn bigger(h: HashMap<&str, i32>) -> Option<i32> {
+    h.into_values().max()
+}
+
+//* the biggest value with every key that has it, keys sorted
+pub fn biggest_entries<'a>(h: &HashMap<&'a str, i32>) -> Vec<(&'a str, i32)> {
+    top_k(h.iter().map(|(&k, &v)| (k, v)), 1, Ties::KeepAll)
 }
@@ -11,6 +11,161 @@ fn main() {
 
     println!(
         "The biggest of the elements in the HashMap is {}",
-        bigger(hash)
+        bigger(hash).unwrap()
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //* xorshift, enough to make reproducible streams without a dependency
+    fn stream(len: usize, mut state: u64) -> Vec<u32> {
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                //* skewed: key i comes up about twice as often as key i + 1 for small keys
+                let r = (state % 1_000_000) as f64 / 1_000_000.0;
+                (-(1.0 - r).ln() * 4.0) as u32 + (state >> 40) as u32 % 3 * 100
+            })
+            .collect()
+    }
+
+    fn exact(items: &[u32]) -> HashMap<u32, u64> {
+        let mut counts = HashMap::new();
+        for &x in items {
+            *counts.entry(x).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_bigger() {
+        let hash = HashMap::from_iter([("Daniel", 122), ("Ashley", 333), ("Katie", 334), ("Robert", 14)]);
+        assert_eq!(bigger(hash), Some(334));
+        assert_eq!(bigger(HashMap::new()), None);
+        assert_eq!(bigger(HashMap::from_iter([("zero", 0)])), Some(0));
+        let hash = HashMap::from_iter([("b", 7), ("a", 7), ("c", 1)]);
+        assert_eq!(biggest_entries(&hash), [("a", 7), ("b", 7)]);
+        assert_eq!(biggest_entries(&HashMap::new()), []);
+    }
+
+    #[test]
+    fn test_top_k_ties() {
+        let scores = [("ann", 5), ("bob", 9), ("cid", 5), ("dan", 7), ("eve", 5), ("fay", 1)];
+        assert_eq!(top_k(scores, 2, Ties::ByKey), [("bob", 9), ("dan", 7)]);
+        assert_eq!(top_k(scores, 3, Ties::ByKey), [("bob", 9), ("dan", 7), ("ann", 5)]);
+        assert_eq!(top_k(scores, 3, Ties::KeepAll), [("bob", 9), ("dan", 7), ("ann", 5), ("cid", 5), ("eve", 5)]);
+        assert_eq!(top_k(scores, 3, Ties::DropAll), [("bob", 9), ("dan", 7)]);
+        //* no tie across the boundary, nothing to keep or drop
+        assert_eq!(top_k(scores, 5, Ties::DropAll).len(), 5);
+        assert_eq!(top_k(scores, 10, Ties::DropAll).len(), 6);
+        assert_eq!(top_k(scores, 0, Ties::KeepAll), []);
+    }
+
+    #[test]
+    fn test_top_k_matches_sort() {
+        let items = stream(5_000, 7);
+        let counts: Vec<(u32, u64)> = exact(&items).into_iter().collect();
+        let mut sorted = counts.clone();
+        sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        for k in [1, 3, 10, 50] {
+            assert_eq!(top_k(counts.clone(), k, Ties::ByKey), sorted[..k.min(sorted.len())]);
+        }
+    }
+
+    #[test]
+    fn test_misra_gries_bounds() {
+        let items = stream(100_000, 42);
+        let counts = exact(&items);
+        for capacity in [5, 20, 100] {
+            let mut mg = MisraGries::new(capacity).unwrap();
+            for &x in &items {
+                mg.insert(x);
+            }
+            let bound = mg.error_bound();
+            assert!(bound <= mg.len() / (capacity as u64 + 1), "{} counters", capacity);
+            for (key, &count) in &counts {
+                let estimate = mg.estimate(key);
+                assert!(estimate <= count && count <= estimate + bound, "{} counters, key {}", capacity, key);
+                if count > mg.len() / (capacity as u64 + 1) {
+                    assert!(estimate > 0, "{} counters lost heavy key {}", capacity, key);
+                }
+            }
+            //* the true 5% keys are all reported, once there are enough counters to promise it
+            if capacity < 19 {
+                continue;
+            }
+            let hitters: Vec<u32> = mg.heavy_hitters(0.05).into_iter().map(|(k, _)| k).collect();
+            for (key, &count) in &counts {
+                if count as f64 > 0.05 * items.len() as f64 {
+                    assert!(hitters.contains(key), "{} counters missed {}", capacity, key);
+                }
+            }
+        }
+        let mut mg = MisraGries::new(2).unwrap();
+        for word in "a b a c a d b a".split(' ') {
+            mg.insert(word);
+        }
+        assert_eq!(mg.candidates(), [("a", 2)]);
+        //* a was seen 4 times, within the bound of 2
+        assert_eq!(mg.error_bound(), 2);
+
+        assert_eq!(MisraGries::<u32>::new(0).unwrap_err(), SketchError::EmptyTable);
+        assert_eq!(MisraGries::<u32>::new(usize::MAX).unwrap_err(), SketchError::TooLarge);
+    }
+
+    #[test]
+    fn test_count_min_bounds() {
+        let items = stream(200_000, 99);
+        let counts = exact(&items);
+        let mut sketch = CountMin::with_error(0.01, 0.01).unwrap();
+        assert_eq!((sketch.width(), sketch.depth()), (272, 5));
+        for &x in &items {
+            sketch.add(&x, 1);
+        }
+        assert_eq!(sketch.total(), items.len() as u64);
+        let bound = sketch.error_bound();
+        let mut over = 0;
+        for (key, &count) in &counts {
+            let estimate = sketch.estimate(key);
+            assert!(estimate >= count);
+            if estimate > count + bound {
+                over += 1;
+            }
+        }
+        //* allowed: a fraction delta of the keys, with some slack for such a small sample
+        assert!(over as f64 <= (counts.len() as f64 * sketch.failure_probability() * 3.0).max(1.0), "{}", over);
+        //* keys that were never added are estimated at most by the bound too, most of the time
+        let unseen = (1_000_000..1_001_000u32).filter(|k| sketch.estimate(k) > bound).count();
+        assert!(unseen <= 20, "{}", unseen);
+    }
+
+    #[test]
+    fn test_count_min_small() {
+        let mut sketch = CountMin::new(64, 4).unwrap();
+        assert_eq!(sketch.add("login", 3), 3);
+        sketch.add("logout", 1);
+        assert_eq!(sketch.add("login", 2), 5);
+        assert!(sketch.estimate("login") >= 5);
+        assert_eq!(sketch.estimate("never"), 0);
+        assert_eq!(CountMin::new(0, 3), Err(SketchError::EmptyTable));
+        assert_eq!(CountMin::with_error(1.5, 0.1), Err(SketchError::BadParameter(1.5)));
+        assert_eq!(CountMin::with_error(0.1, 0.0), Err(SketchError::BadParameter(0.0)));
+        assert_eq!(CountMin::with_error(1e-20, 0.5), Err(SketchError::TooLarge));
+        assert_eq!(CountMin::new(usize::MAX, 2), Err(SketchError::TooLarge));
+        assert_eq!(CountMin::new(usize::MAX / 4, 1), Err(SketchError::TooLarge));
+    }
+
+    #[test]
+    fn test_count_min_saturates() {
+        let mut sketch = CountMin::new(8, 2).unwrap();
+        assert_eq!(sketch.add("big", u64::MAX), u64::MAX);
+        assert_eq!(sketch.add("big", 1), u64::MAX);
+        sketch.add("other", 5);
+        assert_eq!(sketch.total(), u64::MAX);
+        assert_eq!(sketch.estimate("big"), u64::MAX);
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::SketchError;
+
+//* Misra–Gries frequent items: at most `capacity` counters, whatever the number of distinct keys.
+//* After n items, a counter is at most n / (capacity + 1) below the true count of its key, so every
+//* key seen more than n / (capacity + 1) times is sure to still have one
+#[derive(Debug, Clone)]
+pub struct MisraGries<K> {
+    capacity: usize,
+    counters: HashMap<K, u64>,
+    seen: u64,
+    //* how much was taken off every counter in total, the exact error bound
+    decremented: u64,
+}
+
+impl<K: Hash + Eq + Clone> MisraGries<K> {
+    pub fn new(capacity: usize) -> Result<Self, SketchError> {
+        if capacity == 0 {
+            return Err(SketchError::EmptyTable);
+        }
+        //* the table never holds more than `capacity` keys: a new key that finds it full is not inserted,
+        //* it only makes every counter go down by one
+        let mut counters = HashMap::new();
+        counters.try_reserve(capacity).map_err(|_| SketchError::TooLarge)?;
+        Ok(MisraGries { capacity, counters, seen: 0, decremented: 0 })
+    }
+
+    pub fn insert(&mut self, key: K) {
+        self.seen += 1;
+        if let Some(count) = self.counters.get_mut(&key) {
+            *count += 1;
+        } else if self.counters.len() < self.capacity {
+            self.counters.insert(key, 1);
+        } else {
+            //* no room: the new key and every counter lose one, and the counters that reach 0 go
+            self.decremented += 1;
+            self.counters.retain(|_, count| {
+                *count -= 1;
+                *count > 0
+            });
+        }
+    }
+
+    //* number of items inserted so far
+    pub fn len(&self) -> u64 {
+        self.seen
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seen == 0
+    }
+
+    //* a lower bound of the true count, off by at most `error_bound()`
+    pub fn estimate(&self, key: &K) -> u64 {
+        self.counters.get(key).copied().unwrap_or(0)
+    }
+
+    //* never more than len() / (capacity + 1)
+    pub fn error_bound(&self) -> u64 {
+        self.decremented
+    }
+
+    //* every key that still has a counter, the biggest first; equal counts come in no particular order
+    pub fn candidates(&self) -> Vec<(K, u64)> {
+        let mut candidates: Vec<(K, u64)> = self.counters.iter().map(|(k, &c)| (k.clone(), c)).collect();
+        candidates.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        candidates
+    }
+
+    //* keys that may appear more than `fraction` of the time; every key that really does is in the list
+    //* (when fraction >= 1 / (capacity + 1)), but some of them may be below it
+    pub fn heavy_hitters(&self, fraction: f64) -> Vec<(K, u64)> {
+        let threshold = fraction * self.seen as f64;
+        self.candidates()
+            .into_iter()
+            .filter(|&(_, count)| (count + self.decremented) as f64 > threshold)
+            .collect()
+    }
+}
@@ -0,0 +1,38 @@
+//* what to do when entries outside the top k have the same value as the k-th one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ties {
+    //* keep them too, so the result can be longer than k
+    KeepAll,
+    //* drop every entry with that value, so the result can be shorter than k but nothing is picked arbitrarily
+    DropAll,
+    //* exactly k entries: among equal values the smallest keys win
+    ByKey,
+}
+
+//* biggest values first, equal values by key; a selection pass puts the k-th entry in place in O(n),
+//* so only the kept entries get sorted
+pub fn top_k<K: Ord, V: Ord, I: IntoIterator<Item = (K, V)>>(entries: I, k: usize, ties: Ties) -> Vec<(K, V)> {
+    let order = |a: &(K, V), b: &(K, V)| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0));
+    let mut entries: Vec<(K, V)> = entries.into_iter().collect();
+    if k == 0 {
+        return Vec::new();
+    }
+    if k < entries.len() {
+        entries.select_nth_unstable_by(k - 1, order);
+        let mut rest = entries.split_off(k);
+        //* the k-th entry, taken out so the others can be compared to it while `entries` changes
+        let kth = entries.pop().expect("k > 0");
+        let tied = |e: &(K, V)| e.1 == kth.1;
+        match ties {
+            Ties::KeepAll => {
+                rest.retain(tied);
+                entries.push(kth);
+                entries.append(&mut rest);
+            }
+            Ties::DropAll if rest.iter().any(tied) => entries.retain(|e| !tied(e)),
+            _ => entries.push(kth),
+        }
+    }
+    entries.sort_unstable_by(order);
+    entries
+}
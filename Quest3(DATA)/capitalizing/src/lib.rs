@@ -1,43 +1,30 @@
+mod title;
+pub use title::{capitalize, swap_case, titlecase_char, Style, TitleCase};
+
+//* titlecase, not uppercase, for the first letter: "ǆungla" -> "ǅungla"
 pub fn capitalize_first(input: &str) -> String {
-    let mut chars = input.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(), 
-    }
+    capitalize(input, false)
 }
 
+//* every word gets a capital, the rest of it is left as it was; see `TitleCase` for style-guide rules
 pub fn title_case(input: &str) -> String {
     let mut result = String::new();
-    let mut counter = 0;
+    let mut word = String::new();
 
     for c in input.chars() {
         if c.is_whitespace() {
+            result.push_str(&capitalize(&word, true));
+            word.clear();
             result.push(c);
-            counter = 0;
         } else {
-            if counter == 0 {
-                result.push_str(&c.to_uppercase().collect::<String>());
-                counter += 1;
-            } else {
-                result.push(c);
-            }
+            word.push(c);
         }
     }
+    result.push_str(&capitalize(&word, true));
 
     result
 }
-pub fn change_case(input: &str) -> String {
-    input
-    .chars()
-    .map(|c| {
-        if c.is_uppercase() {
-            c.to_lowercase().to_string()
-        }else if c.is_lowercase() {
-            c.to_uppercase().to_string()
-        } else {
-            c.to_string()
-        }
-    })
-    .collect::<String>()
 
-}
\ No newline at end of file
+pub fn change_case(input: &str) -> String {
+    swap_case(input)
+}
@@ -10,4 +10,97 @@ fn main() {
 // Joe is missing
 // Jill Is Leaving A
 // HEllO thERE
-// $
\ No newline at end of file
+// $
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basics() {
+        assert_eq!(capitalize_first("joe is missing"), "Joe is missing");
+        assert_eq!(title_case("jill is leaving A"), "Jill Is Leaving A");
+        assert_eq!(change_case("heLLo THere"), "HEllO thERE");
+        assert_eq!(capitalize_first(""), "");
+        assert_eq!(title_case("  two  spaces "), "  Two  Spaces ");
+    }
+
+    #[test]
+    fn test_special_casing() {
+        assert_eq!(change_case("straße"), "STRASSE");
+        assert_eq!(capitalize_first("ßa"), "Ssa");
+        assert_eq!(capitalize_first("ǆungla"), "ǅungla");
+        assert_eq!(capitalize_first("ﬁre"), "Fire");
+        //* the iota subscript stays a subscript in the titlecase, the uppercase spells it out
+        assert_eq!(capitalize_first("ᾳδης"), "ᾼδης");
+        assert_eq!(change_case("ᾳ"), "ΑΙ");
+        assert_eq!(titlecase_char('ᾀ'), "ᾈ");
+        assert_eq!(titlecase_char('ᾧ'), "ᾯ");
+        assert_eq!(titlecase_char('ᾘ'), "ᾘ");
+        assert_eq!(titlecase_char('ῳ'), "ῼ");
+        assert_eq!(titlecase_char('ᾷ'), "\u{391}\u{342}\u{345}");
+        assert_eq!(titlecase_char('ῴ'), "\u{38F}\u{345}");
+        assert_eq!(titlecase_char('ა'), "ა");
+        assert_eq!(titlecase_char('ö'), "Ö");
+        assert_eq!(change_case("ΟΔΥΣΣΕΥΣ ΣΑΣ"), "οδυσσευς σας");
+        assert_eq!(change_case("Σ"), "σ");
+        assert_eq!(title_case("ijsselmeer en ijs"), "IJsselmeer En IJs");
+        assert_eq!(capitalize_first("ijs"), "Ijs");
+        assert_eq!(title_case("élan e\u{301}tude"), "Élan E\u{301}tude");
+    }
+
+    #[test]
+    fn test_styles() {
+        let title = "the lord of the rings: the return of the king";
+        assert_eq!(TitleCase::new(Style::Chicago).apply(title), "The Lord of the Rings: The Return of the King");
+        assert_eq!(TitleCase::new(Style::Ap).apply(title), "The Lord of the Rings: The Return of the King");
+
+        let title = "a walk through the woods with friends, so to speak";
+        assert_eq!(
+            TitleCase::new(Style::Chicago).apply(title),
+            "A Walk through the Woods with Friends, So to Speak"
+        );
+        assert_eq!(TitleCase::new(Style::Ap).apply(title), "A Walk Through the Woods With Friends, so to Speak");
+        assert_eq!(
+            TitleCase::new(Style::Sentence).apply("The Best Day Of My Life. Or Not?"),
+            "The best day of my life. Or not?"
+        );
+        let sentence = TitleCase::new(Style::Sentence);
+        assert_eq!(sentence.apply("Yesterday I Went To Paris"), "Yesterday I went to paris");
+        assert_eq!(sentence.apply("what i'm saying is I’ve seen it, i'd say"), "What I'm saying is I’ve seen it, I'd say");
+        assert_eq!(sentence.apply("IM IN IT: ID IS ILL"), "Im in it: Id is ill");
+        //* the last word is capitalized even when small
+        assert_eq!(TitleCase::new(Style::Chicago).apply("what are you looking at"), "What Are You Looking At");
+        assert_eq!(TitleCase::new(Style::Chicago).apply("state-of-the-art design"), "State-of-the-Art Design");
+    }
+
+    #[test]
+    fn test_acronyms_and_exceptions() {
+        let chicago = TitleCase::new(Style::Chicago);
+        assert_eq!(chicago.apply("how NASA uses the iPhone"), "How NASA Uses the iPhone");
+        assert_eq!(chicago.apply("don't stop me now"), "Don't Stop Me Now");
+        //* all caps in, no acronym can be told apart
+        assert_eq!(chicago.apply("WAR AND PEACE"), "War and Peace");
+        //* 'ß' and 'º' have no single capital, they don't make a title in capitals look mixed-case
+        assert_eq!(chicago.apply("STRASSE UND ßAND"), "Strasse Und Ssand");
+        assert_eq!(chicago.apply("Nº 5 FOR THE NIGHT"), "Nº 5 for the Night");
+        assert_eq!(chicago.apply("STRASSE UND ßand"), "STRASSE UND Ssand");
+
+        let custom = TitleCase::new(Style::Sentence).with_exceptions(["GitHub", "Rust", "macOS"]);
+        assert_eq!(custom.apply("MACOS tips for rust users on github"), "macOS tips for Rust users on GitHub");
+        let custom = TitleCase::new(Style::Chicago).with_exceptions(["van", "eBay"]);
+        assert_eq!(custom.apply("a portrait of vincent van gogh sold on ebay"), "A Portrait of Vincent van Gogh Sold on eBay");
+    }
+
+    #[test]
+    fn test_unicode_titles() {
+        let chicago = TitleCase::new(Style::Chicago);
+        assert_eq!(chicago.apply("die straße der ijsselmeer"), "Die Straße Der IJsselmeer");
+        assert_eq!(chicago.apply("ΟΔΥΣΣΕΥΣ and the sea"), "ΟΔΥΣΣΕΥΣ and the Sea");
+        assert_eq!(TitleCase::new(Style::Sentence).apply("ΟΔΥΣΣΕΥΣ Ο ΣΟΦΟΣ"), "Οδυσσευς ο σοφος");
+        assert_eq!(chicago.apply("ǆungla of ǉudi"), "ǅungla of ǈudi");
+        //* the titlo and the virama are marks inside the word, not word breaks
+        assert_eq!(chicago.apply("а\u{483}в of the рим"), "А\u{483}в of the Рим");
+        assert_eq!(chicago.apply("x\u{94D}y"), "X\u{94D}y");
+        assert_eq!(chicago.apply(""), "");
+    }
+}
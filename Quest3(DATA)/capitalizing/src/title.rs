@@ -0,0 +1,241 @@
+//* title-casing engine: which words get a capital depends on the style, the rest is about doing it
+//* right for every script ("ß" -> "Ss", final sigma, Dutch "IJ", ...)
+
+use reverse_string::{grapheme_break, Gcb};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    //* Associated Press: small words are lowercase only when they have 3 letters or less ("Walk With Me")
+    Ap,
+    //* Chicago Manual of Style: articles, prepositions of any length and and/but/for/or/nor are lowercase
+    Chicago,
+    //* only the first word (and the first after a '.', '!', '?' or ':') gets a capital
+    Sentence,
+}
+
+const ARTICLES: [&str; 3] = ["a", "an", "the"];
+const CONJUNCTIONS: [&str; 5] = ["and", "but", "for", "or", "nor"];
+//* the ones AP also keeps lowercase, next to the short prepositions
+const AP_CONJUNCTIONS: [&str; 2] = ["so", "yet"];
+const PREPOSITIONS: [&str; 52] = [
+    "about", "above", "across", "after", "against", "along", "among", "around", "as", "at", "before", "behind", "below",
+    "beneath", "beside", "between", "beyond", "by", "down", "during", "except", "for", "from", "in", "inside", "into",
+    "like", "near", "of", "off", "on", "onto", "out", "outside", "over", "past", "per", "since", "through",
+    "throughout", "till", "to", "toward", "under", "underneath", "until", "up", "upon", "via", "with", "within",
+    "without",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitleCase {
+    pub style: Style,
+    //* words written exactly like this wherever they appear, matched ignoring case: "iPhone",
+    //* "GitHub", "NASA", or "de" to keep a particle lowercase
+    pub exceptions: Vec<String>,
+    //* a word starting with "ij" gets "IJ" ("IJsselmeer"), as Dutch writes it
+    pub dutch_ij: bool,
+}
+
+impl Default for TitleCase {
+    fn default() -> Self {
+        TitleCase { style: Style::Chicago, exceptions: Vec::new(), dutch_ij: true }
+    }
+}
+
+impl TitleCase {
+    pub fn new(style: Style) -> Self {
+        TitleCase { style, ..Self::default() }
+    }
+
+    pub fn with_exceptions<I: IntoIterator<Item = S>, S: Into<String>>(mut self, words: I) -> Self {
+        self.exceptions.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    fn is_small(&self, lower: &str) -> bool {
+        match self.style {
+            Style::Chicago => ARTICLES.contains(&lower) || CONJUNCTIONS.contains(&lower) || PREPOSITIONS.contains(&lower),
+            Style::Ap => {
+                lower.chars().count() <= 3
+                    && (ARTICLES.contains(&lower)
+                        || CONJUNCTIONS.contains(&lower)
+                        || AP_CONJUNCTIONS.contains(&lower)
+                        || PREPOSITIONS.contains(&lower))
+            }
+            Style::Sentence => true,
+        }
+    }
+
+    pub fn apply(&self, input: &str) -> String {
+        //* a title typed in capitals has no acronyms to keep, everything in it looks like one
+        let shouting = !input.chars().any(breaks_capitals);
+        let spans = words(input);
+        let last = spans.len().saturating_sub(1);
+        let mut out = String::with_capacity(input.len());
+        let mut copied = 0;
+        let mut starts_sentence = true;
+        for (n, &(start, end)) in spans.iter().enumerate() {
+            let gap = &input[copied..start];
+            if n > 0 && gap.contains(['.', '!', '?', ':']) {
+                starts_sentence = true;
+            }
+            out.push_str(gap);
+            let word = &input[start..end];
+            //* after a hyphen the part is a word of its own, but not the start of a sentence
+            let hyphenated = gap.ends_with('-') && n > 0;
+            out.push_str(&self.word(word, starts_sentence && !hyphenated, n == last, shouting));
+            starts_sentence = false;
+            copied = end;
+        }
+        out.push_str(&input[copied..]);
+        out
+    }
+
+    fn word(&self, word: &str, first: bool, last: bool, shouting: bool) -> String {
+        let lower = word.to_lowercase();
+        if let Some(exception) = self.exceptions.iter().find(|e| e.to_lowercase() == lower) {
+            return exception.clone();
+        }
+        if !shouting && is_acronym_or_brand(word) {
+            return word.to_string();
+        }
+        let capital = match self.style {
+            Style::Sentence => first || is_pronoun_i(&lower),
+            _ => first || last || !self.is_small(&lower),
+        };
+        if capital {
+            capitalize(&lower, self.dutch_ij)
+        } else {
+            lower
+        }
+    }
+}
+
+//* a lowercase letter that text in capitals would not have: 'ß' stays in "STRAßE" (ẞ is rare) and
+//* 'º' in "Nº" has no capital at all
+fn breaks_capitals(c: char) -> bool {
+    c.is_lowercase() && c != 'ß' && !c.to_uppercase().eq([c])
+}
+
+//* "NASA", "iPhone", "McDonald": a capital somewhere after the first letter means it was written
+//* that way on purpose. A single capital letter ("A", "I") is not one
+fn is_acronym_or_brand(word: &str) -> bool {
+    word.chars().skip(1).any(char::is_uppercase)
+}
+
+//* "I" and its contractions ("I'm", "I’ve", "I'd", "I'll") are capitalized even in sentence case
+fn is_pronoun_i(lower: &str) -> bool {
+    match lower.strip_prefix('i') {
+        Some("") => true,
+        Some(rest) => rest.strip_prefix(['\'', '’']).is_some_and(|r| matches!(r, "m" | "ve" | "d" | "ll")),
+        None => false,
+    }
+}
+
+//* byte ranges of the words: letters, digits and marks, with apostrophes inside them ("don't")
+fn words(input: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    let mut chars = input.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let in_word = c.is_alphanumeric() || is_mark(c);
+        let inner_apostrophe = matches!(c, '\'' | '’')
+            && start.is_some()
+            && chars.peek().is_some_and(|&(_, n)| n.is_alphanumeric());
+        match (start, in_word || inner_apostrophe) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                spans.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, input.len()));
+    }
+    spans
+}
+
+//* a char that never starts a grapheme cluster (UAX #29 GB9, GB9a): "e\u{301}", a virama or a vowel sign
+//* belongs to the word of the letter before it
+fn is_mark(c: char) -> bool {
+    matches!(grapheme_break(c), Gcb::Extend | Gcb::SpacingMark | Gcb::Zwj)
+}
+
+//* Unicode titlecase of a char: the full list of chars where it differs from the uppercase
+//* (UnicodeData.txt and SpecialCasing.txt). Digraphs and ligatures only get their first part capitalized
+//* ("ǆ" -> "ǅ", "ß" -> "Ss", "ﬁ" -> "Fi"), Greek iota subscripts stay subscripts ("ᾳ" -> "ᾼ", not "ΑΙ")
+//* and Georgian Mkhedruli letters are their own titlecase
+pub fn titlecase_char(c: char) -> String {
+    match c {
+        'ß' => "Ss".to_string(),
+        'Ǆ' | 'ǅ' | 'ǆ' => "ǅ".to_string(),
+        'Ǉ' | 'ǈ' | 'ǉ' => "ǈ".to_string(),
+        'Ǌ' | 'ǋ' | 'ǌ' => "ǋ".to_string(),
+        'Ǳ' | 'ǲ' | 'ǳ' => "ǲ".to_string(),
+        'ﬀ' => "Ff".to_string(),
+        'ﬁ' => "Fi".to_string(),
+        'ﬂ' => "Fl".to_string(),
+        'ﬃ' => "Ffi".to_string(),
+        'ﬄ' => "Ffl".to_string(),
+        'ﬅ' | 'ﬆ' => "St".to_string(),
+        'և' => "Եւ".to_string(),
+        'ﬓ' => "Մն".to_string(),
+        'ﬔ' => "Մե".to_string(),
+        'ﬕ' => "Մի".to_string(),
+        'ﬖ' => "Վն".to_string(),
+        'ﬗ' => "Մխ".to_string(),
+        'ŉ' => "ʼN".to_string(),
+        //* ᾀ..ᾇ -> ᾈ..ᾏ, ᾐ..ᾗ -> ᾘ..ᾟ, ᾠ..ᾧ -> ᾨ..ᾯ, and the titlecase ones map to themselves
+        '\u{1F80}'..='\u{1FAF}' => char::from_u32(c as u32 | 0x8).into_iter().collect(),
+        'ᾳ' | 'ᾼ' => "ᾼ".to_string(),
+        'ῃ' | 'ῌ' => "ῌ".to_string(),
+        'ῳ' | 'ῼ' => "ῼ".to_string(),
+        //* no precomposed capital with both an accent and a subscript, the ypogegrammeni is kept as U+0345
+        'ᾲ' => "\u{1FBA}\u{345}".to_string(),
+        'ᾴ' => "\u{386}\u{345}".to_string(),
+        'ᾷ' => "\u{391}\u{342}\u{345}".to_string(),
+        'ῂ' => "\u{1FCA}\u{345}".to_string(),
+        'ῄ' => "\u{389}\u{345}".to_string(),
+        'ῇ' => "\u{397}\u{342}\u{345}".to_string(),
+        'ῲ' => "\u{1FFA}\u{345}".to_string(),
+        'ῴ' => "\u{38F}\u{345}".to_string(),
+        'ῷ' => "\u{3A9}\u{342}\u{345}".to_string(),
+        '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}' => c.to_string(),
+        _ => c.to_uppercase().collect(),
+    }
+}
+
+//* titlecases the first letter and leaves the rest as it is; "ijs" -> "IJs" when `dutch_ij` is set
+pub fn capitalize(word: &str, dutch_ij: bool) -> String {
+    if dutch_ij {
+        if let Some(rest) = word.strip_prefix("ij").or_else(|| word.strip_prefix("Ij")) {
+            return format!("IJ{}", rest);
+        }
+    }
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => titlecase_char(first) + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+//* case of one char inverted, with Σ becoming ς at the end of a word like `str::to_lowercase` does
+pub fn swap_case(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::with_capacity(input.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c == 'Σ' {
+            let after_letter = i > 0 && chars[i - 1].is_alphabetic();
+            let ends_word = !chars.get(i + 1).is_some_and(|n| n.is_alphabetic());
+            out.push(if after_letter && ends_word { 'ς' } else { 'σ' });
+        } else if c.is_uppercase() {
+            out.extend(c.to_lowercase());
+        } else if c.is_lowercase() {
+            out.extend(c.to_uppercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,148 @@
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Card, CardError, Rank, Suit};
+
+//* what can come out of a deck: one of the 52 cards, or a joker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeckCard {
+    Regular(Card),
+    Joker,
+}
+
+impl DeckCard {
+    pub fn card(self) -> Option<Card> {
+        match self {
+            DeckCard::Regular(card) => Some(card),
+            DeckCard::Joker => None,
+        }
+    }
+}
+
+impl fmt::Display for DeckCard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeckCard::Regular(card) => write!(f, "{}", card),
+            DeckCard::Joker => write!(f, "JK"),
+        }
+    }
+}
+
+impl FromStr for DeckCard {
+    type Err = CardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "JK" | "JOKER" => Ok(DeckCard::Joker),
+            _ => s.parse().map(DeckCard::Regular),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DealError {
+    pub needed: usize,
+    pub left: usize,
+}
+
+impl fmt::Display for DealError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "can't deal {} cards, only {} left", self.needed, self.left)
+    }
+}
+
+impl std::error::Error for DealError {}
+
+//* one or more 52-card decks, with or without jokers, mixed together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    //* the top of the deck is the end of the vec, so drawing is a pop
+    cards: Vec<DeckCard>,
+}
+
+impl Deck {
+    //* a fresh 52-card deck: hearts, diamonds, spades, clubs, each from ace to king, ace of hearts on top
+    pub fn new() -> Self {
+        Self::shoe(1, 0).expect("a single deck always fits in memory")
+    }
+
+    //* None when the deck would not fit in memory, see `shoe`
+    pub fn with_jokers(jokers: usize) -> Option<Self> {
+        Self::shoe(1, jokers)
+    }
+
+    //* `decks` decks of 52 with `jokers` jokers each, one deck after the other, as a casino shoe is filled;
+    //* None when that many cards overflow a usize or can't be allocated
+    pub fn shoe(decks: usize, jokers: usize) -> Option<Self> {
+        let total = jokers.checked_add(52)?.checked_mul(decks)?;
+        let mut cards = Vec::new();
+        cards.try_reserve_exact(total).ok()?;
+        for _ in 0..decks {
+            for suit in Suit::ALL {
+                cards.extend(Rank::ALL.iter().map(|&rank| DeckCard::Regular(Card { suit, rank })));
+            }
+            cards.extend(std::iter::repeat_n(DeckCard::Joker, jokers));
+        }
+        cards.reverse();
+        Some(Deck { cards })
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    //* from the top down
+    pub fn iter(&self) -> impl Iterator<Item = &DeckCard> {
+        self.cards.iter().rev()
+    }
+
+    //* Fisher–Yates: every order is equally likely, given a uniform rng
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.cards.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            self.cards.swap(i, j);
+        }
+    }
+
+    pub fn draw(&mut self) -> Option<DeckCard> {
+        self.cards.pop()
+    }
+
+    //* the top `n` cards, the first drawn first; nothing is drawn if there are fewer than `n`
+    pub fn draw_n(&mut self, n: usize) -> Result<Vec<DeckCard>, DealError> {
+        if n > self.cards.len() {
+            return Err(DealError { needed: n, left: self.cards.len() });
+        }
+        let mut drawn = self.cards.split_off(self.cards.len() - n);
+        drawn.reverse();
+        Ok(drawn)
+    }
+
+    //* one card at a time to each hand in turn, like at the table; nothing is dealt if the deck is too short
+    pub fn deal(&mut self, hands: usize, cards_each: usize) -> Result<Vec<Vec<DeckCard>>, DealError> {
+        //* more cards than a usize can count are more than any deck has
+        let needed = hands.checked_mul(cards_each).ok_or(DealError { needed: usize::MAX, left: self.len() })?;
+        let drawn = self.draw_n(needed)?;
+        let mut dealt = vec![Vec::with_capacity(cards_each); hands];
+        for (i, card) in drawn.into_iter().enumerate() {
+            dealt[i % hands].push(card);
+        }
+        Ok(dealt)
+    }
+
+    //* puts a card back on top
+    pub fn put_back(&mut self, card: DeckCard) {
+        self.cards.push(card);
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,6 +1,13 @@
 use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+mod deck;
+mod poker;
+pub use deck::{DealError, Deck, DeckCard};
+pub use poker::{all_cards, equity, evaluate, Category, Equity, HandError, HandRank};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Suit {
     Heart,
     Diamond,
@@ -8,7 +15,7 @@ pub enum Suit {
     Club,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Rank {
     Ace,
     Number(u8),
@@ -17,47 +24,170 @@ pub enum Rank {
     King,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardError {
+    InvalidSuit(u8),
+    InvalidRank(u8),
+    //* text that isn't a card in "AS" / "10h" / "Qd" notation
+    Parse(String),
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardError::InvalidSuit(v) => write!(f, "invalid suit value, expected 1-4, got {}", v),
+            CardError::InvalidRank(v) => write!(f, "invalid rank value, expected 1-13, got {}", v),
+            CardError::Parse(s) => write!(f, "{:?} is not a card", s),
+        }
+    }
+}
+
+impl std::error::Error for CardError {}
+
 impl Suit {
+    //* in the order of their values 1 to 4
+    pub const ALL: [Suit; 4] = [Suit::Heart, Suit::Diamond, Suit::Spade, Suit::Club];
+
     pub fn random() -> Suit {
-        let mut rng = rand::thread_rng();
-        Self::translate(rng.gen_range(1..=4))
+        Self::random_with(&mut rand::thread_rng())
     }
 
-    pub fn translate(value: u8) -> Suit {
+    //* with a seeded rng the result can be reproduced
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Suit {
+        Self::ALL[rng.gen_range(0..4)]
+    }
+
+    //* 'H', 'D', 'S' or 'C'
+    pub fn letter(self) -> char {
+        match self {
+            Suit::Heart => 'H',
+            Suit::Diamond => 'D',
+            Suit::Spade => 'S',
+            Suit::Club => 'C',
+        }
+    }
+}
+
+impl TryFrom<u8> for Suit {
+    type Error = CardError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            1 => Suit::Heart,
-            2 => Suit::Diamond,
-            3 => Suit::Spade,
-            4 => Suit::Club,
-            _ => panic!("Invalid suit value. Expected 1-4, got {}", value),
+            1..=4 => Ok(Self::ALL[value as usize - 1]),
+            _ => Err(CardError::InvalidSuit(value)),
         }
     }
 }
 
 impl Rank {
+    //* Ace, 2 to 10, Jack, Queen, King: values 1 to 13
+    pub const ALL: [Rank; 13] = [
+        Rank::Ace,
+        Rank::Number(2),
+        Rank::Number(3),
+        Rank::Number(4),
+        Rank::Number(5),
+        Rank::Number(6),
+        Rank::Number(7),
+        Rank::Number(8),
+        Rank::Number(9),
+        Rank::Number(10),
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+    ];
+
     pub fn random() -> Rank {
-        let mut rng = rand::thread_rng();
-        Self::translate(rng.gen_range(1..=13))
+        Self::random_with(&mut rand::thread_rng())
+    }
+
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Rank {
+        Self::ALL[rng.gen_range(0..13)]
     }
 
-    pub fn translate(value: u8) -> Rank {
+    //* 1 for the ace up to 13 for the king
+    pub fn value(self) -> u8 {
+        match self {
+            Rank::Ace => 1,
+            Rank::Number(n) => n,
+            Rank::Jack => 11,
+            Rank::Queen => 12,
+            Rank::King => 13,
+        }
+    }
+}
+
+impl TryFrom<u8> for Rank {
+    type Error = CardError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            1 => Rank::Ace,
-            2..=10 => Rank::Number(value),
-            11 => Rank::Jack,
-            12 => Rank::Queen,
-            13 => Rank::King,
-            _ => panic!("Invalid rank value. Expected 1-13, got {}", value),
+            1..=13 => Ok(Self::ALL[value as usize - 1]),
+            _ => Err(CardError::InvalidRank(value)),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Card {
     pub suit: Suit,
     pub rank: Rank,
 }
 
+impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Self {
+        Card { suit, rank }
+    }
+}
+
+//* rank then suit letter: "AS", "10H", "QD"
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.rank {
+            Rank::Ace => write!(f, "A")?,
+            Rank::Number(n) => write!(f, "{}", n)?,
+            Rank::Jack => write!(f, "J")?,
+            Rank::Queen => write!(f, "Q")?,
+            Rank::King => write!(f, "K")?,
+        }
+        write!(f, "{}", self.suit.letter())
+    }
+}
+
+const NUMBER_RANKS: [&str; 9] = ["2", "3", "4", "5", "6", "7", "8", "9", "10"];
+
+//* case doesn't matter, "T" is accepted for 10 and the suit can be a symbol: "as", "Th", "Q♦"
+impl FromStr for Card {
+    type Err = CardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || CardError::Parse(s.to_string());
+        let trimmed = s.trim();
+        let suit_char = trimmed.chars().next_back().ok_or_else(error)?;
+        let rank_part = &trimmed[..trimmed.len() - suit_char.len_utf8()];
+        let suit = match suit_char.to_ascii_uppercase() {
+            'H' | '♥' | '♡' => Suit::Heart,
+            'D' | '♦' | '♢' => Suit::Diamond,
+            'S' | '♠' | '♤' => Suit::Spade,
+            'C' | '♣' | '♧' => Suit::Club,
+            _ => return Err(error()),
+        };
+        let rank = match rank_part.to_ascii_uppercase().as_str() {
+            "A" => Rank::Ace,
+            "J" => Rank::Jack,
+            "Q" => Rank::Queen,
+            "K" => Rank::King,
+            "T" => Rank::Number(10),
+            //* compared as text, `parse::<u8>` would also take "+5" and "010"
+            n => match NUMBER_RANKS.iter().position(|&d| d == n) {
+                Some(i) => Rank::Number(i as u8 + 2),
+                None => return Err(error()),
+            },
+        };
+        Ok(Card { suit, rank })
+    }
+}
+
 pub fn winner_card(card: &Card) -> bool {
     card.suit == Suit::Spade && card.rank == Rank::Ace
-}
\ No newline at end of file
+}
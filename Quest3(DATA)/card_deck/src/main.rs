@@ -15,4 +15,320 @@ fn main() {
 
 // $ cargo run
 // Your card is Card { suit: Club, rank: Ace }
-// $
\ No newline at end of file
+// $
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    fn card(s: &str) -> Card {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(Suit::try_from(1), Ok(Suit::Heart));
+        assert_eq!(Suit::try_from(4), Ok(Suit::Club));
+        assert_eq!(Suit::try_from(0), Err(CardError::InvalidSuit(0)));
+        assert_eq!(Rank::try_from(1), Ok(Rank::Ace));
+        assert_eq!(Rank::try_from(7), Ok(Rank::Number(7)));
+        assert_eq!(Rank::try_from(13), Ok(Rank::King));
+        assert_eq!(Rank::try_from(14), Err(CardError::InvalidRank(14)));
+        for value in 1..=13 {
+            assert_eq!(Rank::try_from(value).unwrap().value(), value);
+        }
+    }
+
+    #[test]
+    fn test_notation() {
+        assert_eq!(card("AS"), Card::new(Rank::Ace, Suit::Spade));
+        assert_eq!(card("10h"), Card::new(Rank::Number(10), Suit::Heart));
+        assert_eq!(card("Qd"), Card::new(Rank::Queen, Suit::Diamond));
+        assert_eq!(card("th"), card("10H"));
+        assert_eq!(card(" 7♣ "), Card::new(Rank::Number(7), Suit::Club));
+        assert!(winner_card(&card("as")));
+        for bad in ["", "S", "1S", "11H", "AX", "AAS", "10", "+5H", "010h", "05S", "٥H"] {
+            assert_eq!(bad.parse::<Card>(), Err(CardError::Parse(bad.to_string())), "{:?}", bad);
+        }
+        for c in Deck::new().iter() {
+            assert_eq!(c.to_string().parse::<DeckCard>(), Ok(*c));
+        }
+        assert_eq!(card("10h").to_string(), "10H");
+        assert_eq!("joker".parse::<DeckCard>(), Ok(DeckCard::Joker));
+        assert_eq!(DeckCard::Joker.to_string(), "JK");
+    }
+
+    #[test]
+    fn test_deck() {
+        let mut deck = Deck::new();
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.draw(), Some(DeckCard::Regular(card("AH"))));
+        assert_eq!(deck.iter().next(), Some(&DeckCard::Regular(card("2H"))));
+
+        let deck = Deck::with_jokers(2).unwrap();
+        assert_eq!(deck.len(), 54);
+        assert_eq!(deck.iter().filter(|&&c| c == DeckCard::Joker).count(), 2);
+
+        let shoe = Deck::shoe(6, 0).unwrap();
+        assert_eq!(shoe.len(), 312);
+        let mut counts = HashMap::new();
+        for c in shoe.iter() {
+            *counts.entry(*c).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 52);
+        assert!(counts.values().all(|&n| n == 6));
+        assert_eq!(Deck::with_jokers(usize::MAX), None);
+        assert_eq!(Deck::shoe(usize::MAX, 2), None);
+        assert_eq!(Deck::shoe(usize::MAX / 64, 0), None);
+    }
+
+    #[test]
+    fn test_seeded_shuffle() {
+        let shuffled = |seed| {
+            let mut deck = Deck::with_jokers(2).unwrap();
+            deck.shuffle(&mut StdRng::seed_from_u64(seed));
+            deck
+        };
+        assert_eq!(shuffled(7), shuffled(7));
+        assert_ne!(shuffled(7), shuffled(8));
+        let mut sorted: Vec<String> = shuffled(7).iter().map(|c| c.to_string()).collect();
+        let mut fresh: Vec<String> = Deck::with_jokers(2).unwrap().iter().map(|c| c.to_string()).collect();
+        sorted.sort();
+        fresh.sort();
+        assert_eq!(sorted, fresh);
+        let mut rng = StdRng::seed_from_u64(3);
+        let (a, b) = (Suit::random_with(&mut rng), Rank::random_with(&mut rng));
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_eq!((a, b), (Suit::random_with(&mut rng), Rank::random_with(&mut rng)));
+    }
+
+    #[test]
+    fn test_shuffle_is_uniform() {
+        //* 3 cards have 6 orders, each should come up about 1/6 of the time
+        let mut rng = StdRng::seed_from_u64(2024);
+        let mut seen = HashMap::new();
+        for _ in 0..60_000 {
+            let mut deck = Deck::new();
+            deck.draw_n(49).unwrap();
+            deck.shuffle(&mut rng);
+            let order: Vec<DeckCard> = deck.iter().copied().collect();
+            *seen.entry(order).or_insert(0) += 1;
+        }
+        assert_eq!(seen.len(), 6);
+        assert!(seen.values().all(|&n| (9_400..10_600).contains(&n)), "{:?}", seen.values());
+    }
+
+    #[test]
+    fn test_deal() {
+        let mut deck = Deck::new();
+        let hands = deck.deal(4, 5).unwrap();
+        assert_eq!(hands.len(), 4);
+        assert_eq!(hands[0][..2], [DeckCard::Regular(card("AH")), DeckCard::Regular(card("5H"))]);
+        assert_eq!(hands[3][0], DeckCard::Regular(card("4H")));
+        assert_eq!(deck.len(), 32);
+        assert_eq!(deck.deal(7, 5), Err(DealError { needed: 35, left: 32 }));
+        assert_eq!(deck.len(), 32);
+        assert_eq!(deck.deal(usize::MAX, 2), Err(DealError { needed: usize::MAX, left: 32 }));
+        assert_eq!(deck.len(), 32);
+        assert_eq!(deck.draw_n(2).unwrap(), [DeckCard::Regular(card("8D")), DeckCard::Regular(card("9D"))]);
+        deck.put_back(DeckCard::Joker);
+        assert_eq!(deck.draw(), Some(DeckCard::Joker));
+    }
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace().map(card).collect()
+    }
+
+    fn rank(s: &str) -> HandRank {
+        evaluate(&cards(s)).unwrap()
+    }
+
+    #[test]
+    fn test_categories() {
+        let cases = [
+            ("AS KS QS JS 10S", Category::RoyalFlush),
+            ("5D 4D 3D 2D AD", Category::StraightFlush),
+            ("9C 9D 9H 9S 2C", Category::FourOfAKind),
+            ("3C 3D 3H 2S 2C", Category::FullHouse),
+            ("KH 9H 7H 4H 2H", Category::Flush),
+            ("AS 2D 3H 4C 5S", Category::Straight),
+            ("10S JD QH KC AS", Category::Straight),
+            ("7C 7D 7H KS 2C", Category::ThreeOfAKind),
+            ("7C 7D 5H 5S 2C", Category::TwoPair),
+            ("7C 7D 5H 4S 2C", Category::OnePair),
+            ("AC JD 5H 4S 2C", Category::HighCard),
+            //* wrapping around is not a straight
+            ("QS KD AH 2C 3S", Category::HighCard),
+        ];
+        for (hand, category) in cases {
+            assert_eq!(rank(hand).category(), category, "{}", hand);
+        }
+        assert_eq!(Category::FullHouse.to_string(), "full house");
+    }
+
+    #[test]
+    fn test_kickers() {
+        //* the wheel is the lowest straight
+        assert!(rank("AS 2D 3H 4C 5S") < rank("2S 3D 4H 5C 6S"));
+        assert!(rank("5D 4D 3D 2D AD") < rank("6D 5D 4D 3D 2D"));
+        assert!(rank("9C 9D 9H 9S 3C") > rank("9C 9D 9H 9S 2C"));
+        assert!(rank("3C 3D 3H AS AC") < rank("4C 4D 4H 2S 2C"));
+        assert!(rank("KH 9H 7H 4H 3H") > rank("KD 9D 7D 4D 2D"));
+        assert!(rank("7C 7D 5H 5S 3C") > rank("7C 7D 5H 5S 2C"));
+        assert!(rank("7C 7D 6H 6S 2C") > rank("7C 7D 5H 5S AC"));
+        assert!(rank("7C 7D AH 4S 3C") > rank("7H 7S KH QS JC"));
+        assert!(rank("AC JD 5H 4S 3C") > rank("AD JC 5S 4H 2C"));
+        //* suits never break ties
+        assert_eq!(rank("AC JD 5H 4S 3C"), rank("AD JC 5S 4H 3D"));
+    }
+
+    #[test]
+    fn test_best_of_seven() {
+        //* two pair on the board plus a pair in hand: the best two pairs and the best kicker
+        assert_eq!(rank("KS KD 9H 9C 4S 4D 2H"), rank("KS KD 9H 9C 4S"));
+        //* two threes: a full house with the higher one
+        assert_eq!(rank("8S 8D 8H 5C 5S 5D 2H"), rank("8S 8D 8H 5C 5S"));
+        //* six of a suit: the best five of them
+        assert_eq!(rank("AH KH 9H 7H 4H 2H QS"), rank("AH KH 9H 7H 4H"));
+        //* a straight flush hidden in a flush and a straight
+        assert_eq!(rank("9D 8D 7D 6D 5D 4C AD").category(), Category::StraightFlush);
+        assert_eq!(rank("AS 2D 3H 4C 5S 6S 7S"), rank("3H 4C 5S 6S 7S"));
+        assert_eq!(evaluate(&cards("AS KS QS JS")), Err(HandError::WrongCount(4)));
+        assert_eq!(evaluate(&cards("AS KS QS JS AS")), Err(HandError::Duplicate(card("AS"))));
+        for n in [0, 1, 11, 20, 255] {
+            let bad = Card::new(Rank::Number(n), Suit::Spade);
+            let hand = [bad, card("KS"), card("QS"), card("JS"), card("10S")];
+            assert_eq!(evaluate(&hand), Err(HandError::InvalidCard(bad)));
+        }
+    }
+
+    //* every way to choose `k` of the 52 cards, as evaluator order indexes
+    fn combinations(k: usize, f: &mut dyn FnMut(&[Card])) {
+        fn go(deck: &[Card], k: usize, start: usize, hand: &mut Vec<Card>, f: &mut dyn FnMut(&[Card])) {
+            if hand.len() == k {
+                return f(hand);
+            }
+            for i in start..=deck.len() - (k - hand.len()) {
+                hand.push(deck[i]);
+                go(deck, k, i + 1, hand, f);
+                hand.pop();
+            }
+        }
+        go(&all_cards(), k, 0, &mut Vec::new(), f);
+    }
+
+    #[test]
+    fn test_five_card_counts() {
+        let mut counts: HashMap<Category, u32> = HashMap::new();
+        let mut distinct = std::collections::HashSet::new();
+        combinations(5, &mut |hand| {
+            let rank = evaluate(hand).unwrap();
+            *counts.entry(rank.category()).or_insert(0) += 1;
+            distinct.insert(rank);
+        });
+        let expected = [
+            (Category::RoyalFlush, 4),
+            (Category::StraightFlush, 36),
+            (Category::FourOfAKind, 624),
+            (Category::FullHouse, 3_744),
+            (Category::Flush, 5_108),
+            (Category::Straight, 10_200),
+            (Category::ThreeOfAKind, 54_912),
+            (Category::TwoPair, 123_552),
+            (Category::OnePair, 1_098_240),
+            (Category::HighCard, 1_302_540),
+        ];
+        for (category, count) in expected {
+            assert_eq!(counts[&category], count, "{}", category);
+        }
+        assert_eq!(counts.values().sum::<u32>(), 2_598_960);
+        //* 7462 different hand values, royal flush included
+        assert_eq!(distinct.len(), 7_462);
+    }
+
+    #[test]
+    fn test_seven_equals_best_five() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..2_000 {
+            let mut deck = Deck::new();
+            deck.shuffle(&mut rng);
+            let seven: Vec<Card> = deck.draw_n(7).unwrap().into_iter().filter_map(DeckCard::card).collect();
+            let mut best = None;
+            for skip_a in 0..7 {
+                for skip_b in skip_a + 1..7 {
+                    let five: Vec<Card> = (0..7).filter(|&i| i != skip_a && i != skip_b).map(|i| seven[i]).collect();
+                    best = best.max(Some(evaluate(&five).unwrap()));
+                }
+            }
+            assert_eq!(Some(evaluate(&seven).unwrap()), best, "{:?}", seven);
+        }
+    }
+
+    #[test]
+    #[ignore = "133 million hands, run with --release"]
+    fn test_seven_card_counts() {
+        let mut counts: HashMap<Category, u64> = HashMap::new();
+        combinations(7, &mut |hand| *counts.entry(evaluate(hand).unwrap().category()).or_insert(0) += 1);
+        let expected = [
+            (Category::RoyalFlush, 4_324),
+            (Category::StraightFlush, 37_260),
+            (Category::FourOfAKind, 224_848),
+            (Category::FullHouse, 3_473_184),
+            (Category::Flush, 4_047_644),
+            (Category::Straight, 6_180_020),
+            (Category::ThreeOfAKind, 6_461_620),
+            (Category::TwoPair, 31_433_400),
+            (Category::OnePair, 58_627_800),
+            (Category::HighCard, 23_294_460),
+        ];
+        for (category, count) in expected {
+            assert_eq!(counts[&category], count, "{}", category);
+        }
+    }
+
+    #[test]
+    #[ignore = "1.7 million boards, run with --release"]
+    fn test_preflop_equity() {
+        //* the usual figures for aces against kings with no suit in common: 81.06% / 18.55%, 0.38% split
+        let result = equity(&[[card("AH"), card("AD")], [card("KS"), card("KC")]], &[]).unwrap();
+        assert_eq!((result[0].win * 10_000.0).round(), 8106.0);
+        assert_eq!((result[1].win * 10_000.0).round(), 1855.0);
+        assert_eq!((result[0].tie * 10_000.0).round(), 38.0);
+    }
+
+    #[test]
+    fn test_equity() {
+        //* AK of hearts on a two-heart flop and turn against a set of queens: 7 of the 44 rivers win
+        //* (the 9 and queen of hearts give queens full or quads)
+        let hands = [[card("AH"), card("KH")], [card("QS"), card("QD")]];
+        let result = equity(&hands, &cards("2H 7H QC 9S")).unwrap();
+        assert_eq!(result[0].win, 7.0 / 44.0);
+        assert_eq!(result[1].win, 37.0 / 44.0);
+        assert_eq!(result[0].tie, 0.0);
+
+        //* same hand with other suits: always a split
+        let hands = [[card("AH"), card("KD")], [card("AS"), card("KC")]];
+        let result = equity(&hands, &cards("2C 7D QH")).unwrap();
+        assert!(result[0].tie > 0.9 && (result[0].share + result[1].share - 1.0).abs() < 1e-9);
+        assert_eq!(result[0].win, result[1].win);
+
+        //* a finished board is one deal
+        let result = equity(&hands, &cards("2C 7D QH 3S 3D")).unwrap();
+        assert_eq!(result[0], Equity { win: 0.0, tie: 1.0, share: 0.5 });
+
+        //* three hands on the flop: shares add up to one
+        let hands = [[card("AH"), card("AD")], [card("KS"), card("KC")], [card("7C"), card("8C")]];
+        let result = equity(&hands, &cards("6C 9C 2D")).unwrap();
+        assert!((result.iter().map(|e| e.share).sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(result[2].share > result[1].share);
+
+        assert_eq!(equity(&hands, &cards("AH 2S 3S")), Err(HandError::Duplicate(card("AH"))));
+        assert_eq!(equity(&[], &[]), Err(HandError::NoHands));
+        assert_eq!(equity(&hands[..1], &cards("2S 3S 4S 5S 6S 7S")), Err(HandError::BadBoard(6)));
+        let bad = Card::new(Rank::Number(1), Suit::Heart);
+        assert_eq!(equity(&[[bad, card("2C")]], &[]), Err(HandError::InvalidCard(bad)));
+        assert_eq!(equity(&hands, &[bad]), Err(HandError::InvalidCard(bad)));
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt;
+
+use crate::{Card, Rank, Suit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    //* the ace-high straight flush, kept apart because it is usually counted apart
+    RoyalFlush,
+}
+
+impl Category {
+    pub const ALL: [Category; 10] = [
+        Category::HighCard,
+        Category::OnePair,
+        Category::TwoPair,
+        Category::ThreeOfAKind,
+        Category::Straight,
+        Category::Flush,
+        Category::FullHouse,
+        Category::FourOfAKind,
+        Category::StraightFlush,
+        Category::RoyalFlush,
+    ];
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Category::HighCard => "high card",
+            Category::OnePair => "one pair",
+            Category::TwoPair => "two pair",
+            Category::ThreeOfAKind => "three of a kind",
+            Category::Straight => "straight",
+            Category::Flush => "flush",
+            Category::FullHouse => "full house",
+            Category::FourOfAKind => "four of a kind",
+            Category::StraightFlush => "straight flush",
+            Category::RoyalFlush => "royal flush",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+//* the strength of the best 5 cards: a bigger value is a better hand and equal values split the pot.
+//* Packed as the category, then up to five ranks (0 for a two up to 12 for an ace) by importance,
+//* e.g. a full house is the rank of the three then the rank of the two
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandRank(u32);
+
+impl HandRank {
+    pub fn category(self) -> Category {
+        Category::ALL[(self.0 >> 20) as usize]
+    }
+
+    fn new(category: Category, ranks: &[u32]) -> HandRank {
+        let packed = ranks.iter().enumerate().fold(0, |acc, (i, &r)| acc | r << (16 - 4 * i));
+        HandRank((category as u32) << 20 | packed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandError {
+    //* a hand is 5 to 7 cards
+    WrongCount(usize),
+    Duplicate(Card),
+    //* a `Rank::Number` outside 2..=10, which `Card::new` does not reject
+    InvalidCard(Card),
+    //* equity needs a board of at most 5 cards and at least one hand
+    BadBoard(usize),
+    NoHands,
+    //* so many hands that the deck can't complete the board
+    NotEnoughCards,
+}
+
+impl fmt::Display for HandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandError::WrongCount(n) => write!(f, "a hand has 5 to 7 cards, not {}", n),
+            HandError::Duplicate(card) => write!(f, "{} is used twice", card),
+            HandError::InvalidCard(card) => write!(f, "{:?} is not a card of the deck", card),
+            HandError::BadBoard(n) => write!(f, "a board has at most 5 cards, not {}", n),
+            HandError::NoHands => write!(f, "no hands to compare"),
+            HandError::NotEnoughCards => write!(f, "not enough cards left to complete the board"),
+        }
+    }
+}
+
+impl std::error::Error for HandError {}
+
+//* 0 for the two of hearts up to 51 for the ace of clubs: rank * 4 + suit, aces high.
+//* None for ranks like `Rank::Number(1)` that don't read back as themselves
+fn index(card: Card) -> Option<u8> {
+    if Rank::try_from(card.rank.value()) != Ok(card.rank) {
+        return None;
+    }
+    let rank = match card.rank {
+        Rank::Ace => 12,
+        other => other.value() - 2,
+    };
+    let suit = Suit::ALL.iter().position(|&s| s == card.suit)? as u8;
+    Some(rank * 4 + suit)
+}
+
+fn from_index(i: u8) -> Card {
+    let rank = match i / 4 {
+        12 => Rank::Ace,
+        r => Rank::try_from(r + 2).unwrap_or(Rank::Ace),
+    };
+    Card { rank, suit: Suit::ALL[(i % 4) as usize] }
+}
+
+//* rank (0..=12) of the highest card of the best straight in `mask`, the ace also counting as a one
+fn straight_high(mask: u16) -> Option<u32> {
+    //* bit 0 is the low ace, bit r + 1 is rank r
+    let shifted = (mask as u32) << 1 | (mask as u32 >> 12 & 1);
+    (4..=13).rev().find(|&top| shifted >> (top - 4) & 0x1F == 0x1F).map(|top| top - 1)
+}
+
+//* up to five ranks on the stack: the evaluator runs millions of times in `equity`, it must not allocate
+#[derive(Default)]
+struct Ranks {
+    items: [u32; 5],
+    len: usize,
+}
+
+impl Ranks {
+    //* adds the `n` highest ranks set in `mask`, highest first
+    fn top(&mut self, mut mask: u16, n: usize) -> &mut Self {
+        for _ in 0..n {
+            if mask == 0 {
+                break;
+            }
+            let r = mask.ilog2();
+            self.items[self.len] = r;
+            self.len += 1;
+            mask &= !(1 << r);
+        }
+        self
+    }
+
+    fn hand(&self, category: Category) -> HandRank {
+        HandRank::new(category, &self.items[..self.len])
+    }
+}
+
+//* works on 5, 6 or 7 card indexes straight away, without going through the 21 five-card subsets
+fn rank_indexes(cards: &[u8]) -> HandRank {
+    let mut counts = [0u8; 13];
+    let mut suits = [0u16; 4];
+    let mut all = 0u16;
+    for &c in cards {
+        counts[(c / 4) as usize] += 1;
+        suits[(c % 4) as usize] |= 1 << (c / 4);
+        all |= 1 << (c / 4);
+    }
+    let flush = suits.iter().copied().find(|s| s.count_ones() >= 5);
+    if let Some(high) = flush.and_then(straight_high) {
+        let category = if high == 12 { Category::RoyalFlush } else { Category::StraightFlush };
+        return HandRank::new(category, &[high]);
+    }
+    //* ranks having at least 4, 3 and 2 cards
+    let (mut quads, mut trips, mut pairs) = (0u16, 0u16, 0u16);
+    for (r, &n) in counts.iter().enumerate() {
+        if n >= 4 {
+            quads |= 1 << r;
+        }
+        if n >= 3 {
+            trips |= 1 << r;
+        }
+        if n >= 2 {
+            pairs |= 1 << r;
+        }
+    }
+    if let Some(quad) = quads.checked_ilog2() {
+        return Ranks::default().top(quads, 1).top(all & !(1 << quad), 1).hand(Category::FourOfAKind);
+    }
+    let trip = trips.checked_ilog2();
+    if let Some(trip) = trip.filter(|&t| pairs & !(1 << t) != 0) {
+        return Ranks::default().top(trips, 1).top(pairs & !(1 << trip), 1).hand(Category::FullHouse);
+    }
+    if let Some(flush) = flush {
+        return Ranks::default().top(flush, 5).hand(Category::Flush);
+    }
+    if let Some(high) = straight_high(all) {
+        return HandRank::new(Category::Straight, &[high]);
+    }
+    if let Some(trip) = trip {
+        return Ranks::default().top(trips, 1).top(all & !(1 << trip), 2).hand(Category::ThreeOfAKind);
+    }
+    if pairs.count_ones() >= 2 {
+        let mut ranks = Ranks::default();
+        ranks.top(pairs, 2);
+        let kickers = all & !(1 << ranks.items[0]) & !(1 << ranks.items[1]);
+        return ranks.top(kickers, 1).hand(Category::TwoPair);
+    }
+    if pairs != 0 {
+        return Ranks::default().top(pairs, 1).top(all & !pairs, 3).hand(Category::OnePair);
+    }
+    Ranks::default().top(all, 5).hand(Category::HighCard)
+}
+
+fn indexes(cards: &[Card]) -> Result<Vec<u8>, HandError> {
+    let mut seen = 0u64;
+    cards
+        .iter()
+        .map(|&card| {
+            let i = index(card).ok_or(HandError::InvalidCard(card))?;
+            if seen >> i & 1 == 1 {
+                return Err(HandError::Duplicate(card));
+            }
+            seen |= 1 << i;
+            Ok(i)
+        })
+        .collect()
+}
+
+//* the best five-card hand among 5, 6 or 7 cards (hold'em: two hole cards and the board)
+pub fn evaluate(cards: &[Card]) -> Result<HandRank, HandError> {
+    if !(5..=7).contains(&cards.len()) {
+        return Err(HandError::WrongCount(cards.len()));
+    }
+    Ok(rank_indexes(&indexes(cards)?))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Equity {
+    //* fraction of the boards where this hand wins alone
+    pub win: f64,
+    //* fraction of the boards where it shares the best hand with others
+    pub tie: f64,
+    //* what the hand gets back on average: wins, plus its part of every split pot
+    pub share: f64,
+}
+
+//* hold'em equity of every hand, going through every way to complete the board (up to C(48, 5) for
+//* two hands before the flop, about 1.7 million boards)
+pub fn equity(hands: &[[Card; 2]], board: &[Card]) -> Result<Vec<Equity>, HandError> {
+    if hands.is_empty() {
+        return Err(HandError::NoHands);
+    }
+    if board.len() > 5 {
+        return Err(HandError::BadBoard(board.len()));
+    }
+    let known: Vec<Card> = hands.iter().flatten().chain(board).copied().collect();
+    let known = indexes(&known)?;
+    let used = known.iter().fold(0u64, |acc, &i| acc | 1 << i);
+    let deck: Vec<u8> = (0..52).filter(|i| used >> i & 1 == 0).collect();
+    if deck.len() < 5 - board.len() {
+        return Err(HandError::NotEnoughCards);
+    }
+    //* `known` holds the hole cards two by two, then the board
+    let (hole, fixed) = known.split_at(2 * hands.len());
+    let hole: Vec<[u8; 2]> = hole.chunks(2).map(|h| [h[0], h[1]]).collect();
+
+    let mut wins = vec![0u64; hands.len()];
+    let mut ties = vec![0u64; hands.len()];
+    let mut shares = vec![0f64; hands.len()];
+    let mut boards = 0u64;
+    let mut cards = [0u8; 7];
+    let mut ranks = vec![HandRank(0); hands.len()];
+    for_each_combination(&deck, 5 - fixed.len(), &mut |extra| {
+        boards += 1;
+        cards[2..2 + fixed.len()].copy_from_slice(fixed);
+        cards[2 + fixed.len()..].copy_from_slice(extra);
+        for (rank, h) in ranks.iter_mut().zip(&hole) {
+            cards[..2].copy_from_slice(h);
+            *rank = rank_indexes(&cards);
+        }
+        let best = *ranks.iter().max().unwrap_or(&HandRank(0));
+        let winners = ranks.iter().filter(|&&r| r == best).count();
+        for (i, &rank) in ranks.iter().enumerate() {
+            if rank == best {
+                if winners == 1 {
+                    wins[i] += 1;
+                } else {
+                    ties[i] += 1;
+                }
+                shares[i] += 1.0 / winners as f64;
+            }
+        }
+    });
+    let boards = boards as f64;
+    Ok((0..hands.len())
+        .map(|i| Equity { win: wins[i] as f64 / boards, tie: ties[i] as f64 / boards, share: shares[i] / boards })
+        .collect())
+}
+
+//* calls `f` with every `k`-element subset of `items`, in lexicographic order
+fn for_each_combination<F: FnMut(&[u8])>(items: &[u8], k: usize, f: &mut F) {
+    fn go<F: FnMut(&[u8])>(items: &[u8], k: usize, start: usize, picked: &mut Vec<u8>, f: &mut F) {
+        if picked.len() == k {
+            f(picked);
+            return;
+        }
+        for i in start..=items.len() - (k - picked.len()) {
+            picked.push(items[i]);
+            go(items, k, i + 1, picked, f);
+            picked.pop();
+        }
+    }
+    if k <= items.len() {
+        go(items, k, 0, &mut Vec::with_capacity(k), f);
+    }
+}
+
+//* every card of a 52-card deck, from the two of hearts up, in the order the evaluator indexes them
+pub fn all_cards() -> Vec<Card> {
+    (0..52).map(from_index).collect()
+}
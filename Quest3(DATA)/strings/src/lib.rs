@@ -1,3 +1,6 @@
+mod stats;
+pub use stats::{CharClasses, StatsError, TextCounter, TextStats};
+
 pub fn char_length(s: &str) -> usize {
     // let mut count = 0;
     // for _ in s.chars() {
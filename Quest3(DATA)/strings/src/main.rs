@@ -1,10 +1,10 @@
 use strings::*;
 
 fn main() {
-	println!("length of {} = {}", "❤", char_length("❤"));
-	println!("length of {} = {}", "形声字", char_length("形聲字"));
-	println!("length of {} = {}", "change", char_length("change"));
-	println!("length of {} = {}", "😍", char_length("😍"));
+	println!("length of ❤ = {}", char_length("❤"));
+	println!("length of 形声字 = {}", char_length("形聲字"));
+	println!("length of change = {}", char_length("change"));
+	println!("length of 😍 = {}", char_length("😍"));
 }
 
 // $ cargo run
@@ -12,4 +12,99 @@ fn main() {
 // length of 形声字 = 3
 // length of change = 6
 // length of 😍 = 1
-// $
\ No newline at end of file
+// $
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    //* hands out at most `step` bytes per read, to cut chars and "\r\n" in half
+    struct Trickle<'a> {
+        data: &'a [u8],
+        step: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.step.min(buf.len()).min(self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_char_length() {
+        assert_eq!(char_length("❤"), 1);
+        assert_eq!(char_length("形聲字"), 3);
+        assert_eq!(char_length("change"), 6);
+        assert_eq!(char_length("😍"), 1);
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = TextStats::of("Hello, wörld!\r\nline two 42\n\nlast");
+        assert_eq!(stats.bytes, 33);
+        assert_eq!(stats.chars, 32);
+        //* "\r\n" is a single cluster
+        assert_eq!(stats.graphemes, 31);
+        assert_eq!(stats.words, 6);
+        assert_eq!(stats.lines, 4);
+        assert_eq!(stats.longest_line, 13);
+        assert_eq!(
+            stats.classes,
+            CharClasses { whitespace: 7, control: 0, alphabetic: 21, numeric: 2, ascii_punctuation: 2, other: 0 }
+        );
+        assert_eq!(TextStats::of(""), TextStats::default());
+        assert_eq!(TextStats::of("a\n").lines, 1);
+        assert_eq!(TextStats::of("\n\n").lines, 2);
+        let stats = TextStats::of("a\r");
+        assert_eq!((stats.lines, stats.longest_line), (1, 1));
+        assert_eq!(TextStats::of("abc\r\nab\r").longest_line, 3);
+        //* a '\r' inside a line is counted
+        assert_eq!(TextStats::of("a\rb\n").longest_line, 3);
+    }
+
+    #[test]
+    fn test_graphemes_and_classes() {
+        let stats = TextStats::of("e\u{301}🇲🇦 👨‍👩‍👧\u{0}€");
+        assert_eq!(stats.chars, 12);
+        assert_eq!(stats.graphemes, 6);
+        assert_eq!(stats.words, 2);
+        assert_eq!(stats.classes.control, 1);
+        assert_eq!(stats.classes.other, 9);
+    }
+
+    #[test]
+    fn test_reader_matches_str() {
+        let text = "Grüße, 世界!\r\n🇲🇦🇫🇷 e\u{301}\u{302}\r\n\tend";
+        let expected = TextStats::of(text);
+        for step in 1..8 {
+            let stats = TextStats::from_reader(Trickle { data: text.as_bytes(), step }).unwrap();
+            assert_eq!(stats, expected, "step {}", step);
+        }
+        let mut counter = TextCounter::new();
+        for piece in ["Grüße, 世", "界!\r", "\n🇲🇦", "🇫🇷 e\u{301}", "\u{302}\r\n\tend"] {
+            counter.push_str(piece);
+        }
+        assert_eq!(counter.finish(), expected);
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let offset = |data: &[u8], step| match TextStats::from_reader(Trickle { data, step }) {
+            Err(StatsError::InvalidUtf8 { offset }) => Some(offset),
+            _ => None,
+        };
+        for step in [1, 3, 8192] {
+            assert_eq!(offset(b"abc\xFFdef", step), Some(3));
+            assert_eq!(offset("héllo\u{20AC}".as_bytes(), step), None);
+            //* a truncated char at the very end
+            assert_eq!(offset(b"ok\xE2\x82", step), Some(2));
+            //* a lead byte followed by something that can't continue it
+            assert_eq!(offset(b"ab\xE2\x82x", step), Some(2));
+        }
+        let big: Vec<u8> = [&[b'a'; 10_000][..], b"\xC0\xAF"].concat();
+        assert_eq!(offset(&big, 4096), Some(10_000));
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt;
+use std::io::{self, Read};
+
+use reverse_string::GraphemeBreaker;
+
+//* every char falls in exactly one of these, checked in this order
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CharClasses {
+    pub whitespace: usize,
+    //* control chars that aren't whitespace (NUL, ESC, ...)
+    pub control: usize,
+    pub alphabetic: usize,
+    pub numeric: usize,
+    pub ascii_punctuation: usize,
+    //* everything else: symbols and punctuation outside ASCII, emoji, combining marks, ...
+    pub other: usize,
+}
+
+impl CharClasses {
+    fn add(&mut self, c: char) {
+        let count = if c.is_whitespace() {
+            &mut self.whitespace
+        } else if c.is_control() {
+            &mut self.control
+        } else if c.is_alphabetic() {
+            &mut self.alphabetic
+        } else if c.is_numeric() {
+            &mut self.numeric
+        } else if c.is_ascii_punctuation() {
+            &mut self.ascii_punctuation
+        } else {
+            &mut self.other
+        };
+        *count += 1;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextStats {
+    pub bytes: usize,
+    pub chars: usize,
+    pub graphemes: usize,
+    //* runs of non-whitespace chars, like `wc -w`
+    pub words: usize,
+    //* "\n" and "\r\n" end a line; unlike `wc -l` a last line without one still counts, so "a\nb" has 2
+    pub lines: usize,
+    //* in chars, without the line ending
+    pub longest_line: usize,
+    pub classes: CharClasses,
+}
+
+#[derive(Debug)]
+pub enum StatsError {
+    Io(io::Error),
+    //* byte offset, from the start of the stream, of the first byte that isn't valid UTF-8
+    InvalidUtf8 { offset: usize },
+}
+
+impl fmt::Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StatsError::Io(e) => write!(f, "read error: {}", e),
+            StatsError::InvalidUtf8 { offset } => write!(f, "invalid UTF-8 at byte {}", offset),
+        }
+    }
+}
+
+impl std::error::Error for StatsError {}
+
+impl From<io::Error> for StatsError {
+    fn from(e: io::Error) -> Self {
+        StatsError::Io(e)
+    }
+}
+
+//* the running state: text can be fed in any number of pieces, split anywhere between chars
+#[derive(Debug, Clone, Default)]
+pub struct TextCounter {
+    stats: TextStats,
+    breaker: GraphemeBreaker,
+    in_word: bool,
+    //* chars in the current line so far, and whether the last one was a '\r'
+    line_len: usize,
+    after_cr: bool,
+}
+
+impl TextCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.stats.bytes += s.len();
+        for c in s.chars() {
+            self.push(c);
+        }
+    }
+
+    fn push(&mut self, c: char) {
+        let stats = &mut self.stats;
+        stats.chars += 1;
+        stats.classes.add(c);
+        if self.breaker.push(c) {
+            stats.graphemes += 1;
+        }
+        if c.is_whitespace() {
+            self.in_word = false;
+        } else if !self.in_word {
+            self.in_word = true;
+            stats.words += 1;
+        }
+        if c == '\n' {
+            let len = self.line_len_without_cr();
+            self.stats.longest_line = self.stats.longest_line.max(len);
+            self.stats.lines += 1;
+            self.line_len = 0;
+        } else {
+            self.line_len += 1;
+        }
+        self.after_cr = c == '\r';
+    }
+
+    //* the '\r' of a "\r\n", or one the text ends with, belongs to the line ending, not to the line
+    fn line_len_without_cr(&self) -> usize {
+        self.line_len - usize::from(self.after_cr)
+    }
+
+    pub fn finish(mut self) -> TextStats {
+        if self.line_len > 0 {
+            self.stats.lines += 1;
+            self.stats.longest_line = self.stats.longest_line.max(self.line_len_without_cr());
+        }
+        self.stats
+    }
+}
+
+const CHUNK: usize = 8 * 1024;
+
+impl TextStats {
+    pub fn of(s: &str) -> TextStats {
+        let mut counter = TextCounter::new();
+        counter.push_str(s);
+        counter.finish()
+    }
+
+    //* reads until EOF in fixed-size chunks, so the whole input never has to be in memory
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<TextStats, StatsError> {
+        let mut counter = TextCounter::new();
+        let mut buf = vec![0; CHUNK];
+        //* bytes at the start of `buf` left over from the previous read: a char cut in half
+        let mut kept = 0;
+        //* stream offset of buf[0]
+        let mut offset = 0;
+        loop {
+            let n = match reader.read(&mut buf[kept..]) {
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            if n == 0 {
+                if kept > 0 {
+                    return Err(StatsError::InvalidUtf8 { offset });
+                }
+                return Ok(counter.finish());
+            }
+            let len = kept + n;
+            let text = match std::str::from_utf8(&buf[..len]) {
+                Ok(s) => s,
+                //* error_len None: the input just stops in the middle of a char, the next read may finish it
+                Err(e) if e.error_len().is_none() => {
+                    std::str::from_utf8(&buf[..e.valid_up_to()]).expect("valid_up_to ends on a char boundary")
+                }
+                Err(e) => return Err(StatsError::InvalidUtf8 { offset: offset + e.valid_up_to() }),
+            };
+            let valid = text.len();
+            counter.push_str(text);
+            buf.copy_within(valid..len, 0);
+            kept = len - valid;
+            offset += valid;
+        }
+    }
+}